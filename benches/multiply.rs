@@ -0,0 +1,76 @@
+//! Blocked `Matrix::multiply` against the previous dot product per entry and
+//! `Matrix::strassen`.
+//!
+//! Run with `cargo bench --bench multiply`.
+
+use std::{hint::black_box, time::Instant};
+
+use matrix::{matrix, Matrix};
+
+/// The dot product per entry that `multiply` replaced.
+fn naive<const M: usize, const N: usize, const P: usize>(
+    a: &Matrix<M, N>,
+    b: &Matrix<N, P>,
+) -> Matrix<M, P> {
+    let transpose = b.transpose();
+    a.into_iter()
+        .map(|row| transpose.into_iter().map(|col| row.dot(&col)).collect())
+        .collect()
+}
+
+/// Boxed copy of the unit test fixture, which benches can't reach.
+fn fill<const N: usize>(seed: u64) -> Box<Matrix<N, N>> {
+    let mut state = seed;
+    Box::new(matrix(std::array::from_fn(|_| {
+        std::array::from_fn(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.
+        })
+    })))
+}
+
+/// Mean time of one call in microseconds.
+fn time<T>(iterations: u32, mut f: impl FnMut() -> T) -> f64 {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    start.elapsed().as_secs_f64() * 1e6 / iterations as f64
+}
+
+fn compare<const N: usize>(iterations: u32) {
+    let a = fill::<N>(1);
+    let b = fill::<N>(2);
+    assert_eq!(a.multiply(&b), naive(&a, &b));
+    let blocked = time(iterations, || a.multiply(&b));
+    let previous = time(iterations, || naive(&a, &b));
+    let strassen = time(iterations, || a.strassen_with(&b, 128));
+    println!(
+        "{:>4}x{:<4} blocked {:>12.1} µs  previous {:>12.1} µs  speedup {:.2}x  strassen {:>12.1} µs",
+        N,
+        N,
+        blocked,
+        previous,
+        previous / blocked,
+        strassen
+    );
+}
+
+fn main() {
+    // 256x256 temporaries don't fit the default main thread stack on every platform.
+    std::thread::Builder::new()
+        .stack_size(64 << 20)
+        .spawn(|| {
+            compare::<8>(10_000);
+            compare::<32>(1_000);
+            compare::<64>(200);
+            compare::<128>(20);
+            compare::<256>(5);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
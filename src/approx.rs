@@ -0,0 +1,282 @@
+use crate::{
+    line::Intersection, math::Tolerance, parametric::ParametricLine, plane, ConditionedSolution,
+    Equation, LinearSystem, Matrix, Projection, Residual, Solution, Solutions, Vector,
+};
+
+/// Approximate equality, component by component.
+///
+/// Unlike `PartialEq` for `Equation`, which compares the hyperplanes, equations
+/// are compared by their coefficients and constant terms.
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq(other, &Tolerance::Absolute(epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.approx_eq(
+            other,
+            &Tolerance::Combined {
+                absolute: epsilon,
+                relative: max_relative,
+            },
+        )
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.approx_eq(other, &Tolerance::Ulps(max_ulps))
+    }
+}
+
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::math::Tolerance::default())
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n tolerance: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::math::Tolerance::default())
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≉ right` failed\n  left: {:?}\n right: {:?}\n tolerance: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        tolerance.approx_eq(*self, *other)
+    }
+}
+
+impl<const DIM: usize> ApproxEq for Vector<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        (0..DIM).all(|index| self[index].approx_eq(&other[index], tolerance))
+    }
+}
+
+impl<const DIM: usize> ApproxEq for Projection<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.parallel.approx_eq(&other.parallel, tolerance)
+            && self.orthogonal.approx_eq(&other.orthogonal, tolerance)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> ApproxEq for Matrix<ROW, COL> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        (0..ROW).all(|row| self[row].approx_eq(&other[row], tolerance))
+    }
+}
+
+impl<const DIM: usize> ApproxEq for Equation<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.normal_vector
+            .approx_eq(&other.normal_vector, tolerance)
+            && self
+                .constant_term
+                .approx_eq(&other.constant_term, tolerance)
+    }
+}
+
+impl<const DIM: usize, const LEN: usize> ApproxEq for LinearSystem<DIM, LEN> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        (0..LEN).all(|row| self[row].approx_eq(&other[row], tolerance))
+    }
+}
+
+impl<const DIM: usize> ApproxEq for Solution<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        match (self, other) {
+            (Solution::Some(self_vector), Solution::Some(other_vector)) => {
+                self_vector.approx_eq(other_vector, tolerance)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl<const DIM: usize, const K: usize> ApproxEq for Solutions<DIM, K> {
+    /// `NaN` values of columns without solution are ignored.
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.kinds == other.kinds
+            && (0..K).all(|col| self.column(col).approx_eq(&other.column(col), tolerance))
+    }
+}
+
+impl<const DIM: usize> ApproxEq for ConditionedSolution<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.solution.approx_eq(&other.solution, tolerance)
+            && (self.condition_number == other.condition_number
+                || self
+                    .condition_number
+                    .approx_eq(&other.condition_number, tolerance))
+    }
+}
+
+impl<const LEN: usize> ApproxEq for Residual<LEN> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.residuals.approx_eq(&other.residuals, tolerance)
+            && self.norm.approx_eq(&other.norm, tolerance)
+    }
+}
+
+impl ApproxEq for Intersection {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        match (self, other) {
+            (Intersection::Some(self_point), Intersection::Some(other_point)) => {
+                self_point.approx_eq(other_point, tolerance)
+            }
+            (Intersection::None, Intersection::None) => true,
+            (Intersection::Infinity(self_line), Intersection::Infinity(other_line)) => {
+                self_line.approx_eq(other_line, tolerance)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<const DIM: usize> ApproxEq for ParametricLine<DIM> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.base_point.approx_eq(&other.base_point, tolerance)
+            && self.direction.approx_eq(&other.direction, tolerance)
+    }
+}
+
+impl ApproxEq for plane::Intersection {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        use plane::Intersection::*;
+        match (self, other) {
+            (Some(self_line), Some(other_line)) => self_line.approx_eq(other_line, tolerance),
+            (None, None) => true,
+            (Infinity(self_plane), Infinity(other_plane)) => {
+                self_plane.approx_eq(other_plane, tolerance)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for plane::LineIntersection {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        use plane::LineIntersection::*;
+        match (self, other) {
+            (Some(self_point), Some(other_point)) => self_point.approx_eq(other_point, tolerance),
+            (None, None) => true,
+            (Infinity(self_line), Infinity(other_line)) => {
+                self_line.approx_eq(other_line, tolerance)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        equation, line::Intersection, linear_system, math::Tolerance, matrix, vector, Solution,
+    };
+
+    use super::ApproxEq;
+
+    #[test]
+    fn f64_case() {
+        assert!(0.3.abs_diff_eq(&(0.1 + 0.2), 1e-15));
+        assert!(!0.3.abs_diff_eq(&0.31, 1e-3));
+        assert!(1e20.relative_eq(&(1e20 + 1e5), 0., 1e-12));
+        assert!(0.3.ulps_eq(&(0.1 + 0.2), 1));
+        assert!(!0.3.ulps_eq(&0.31, 1000));
+    }
+
+    #[test]
+    fn vector_case() {
+        let v = vector([0.1 + 0.2, 1. / 3.]);
+        assert!(v.abs_diff_eq(&vector([0.3, 0.333333]), 1e-6));
+        assert!(!v.abs_diff_eq(&vector([0.3, 0.333]), 1e-6));
+        assert_approx_eq!(v, vector([0.3, 1. / 3.]));
+        assert_approx_ne!(v, vector([0.3, 0.3]));
+    }
+
+    #[test]
+    fn matrix_case() {
+        let m = matrix([[4., 5.], [7., 1.]]);
+        assert_approx_eq!(
+            m.inverse().unwrap(),
+            matrix([[-1. / 31., 5. / 31.], [7. / 31., -4. / 31.]]),
+            Tolerance::Ulps(4)
+        );
+        assert_approx_ne!(
+            m,
+            matrix([[4., 5.], [7., 1.001]]),
+            Tolerance::Absolute(1e-6)
+        );
+    }
+
+    #[test]
+    fn equation_case() {
+        let e = equation(vector([1., 2.]), 3.);
+        assert_approx_eq!(e, equation(vector([1., 2. + 1e-12]), 3.));
+        assert_eq!(e, e * 2.);
+        assert_approx_ne!(e, e * 2.);
+    }
+
+    #[test]
+    fn linear_system_case() {
+        let e1 = equation(vector([0., 1., 1.]), 1.);
+        let e2 = equation(vector([1., -1., 1.]), 2.);
+        let s = linear_system([e1, e2]);
+        assert_approx_eq!(s, linear_system([e1, e2 * (1. + 1e-15)]));
+        assert_approx_ne!(s, linear_system([e2, e1]));
+    }
+
+    #[test]
+    fn solution_case() {
+        let e1 = equation(vector([0., 1., 1.]), 1.);
+        let e2 = equation(vector([1., -1., 1.]), 2.);
+        let e3 = equation(vector([1., 2., -5.]), 3.);
+        let s = linear_system([e1, e2, e3]);
+        assert_approx_eq!(
+            s.compute_solution(),
+            Solution::Some(vector([23. / 9., 7. / 9., 2. / 9.]))
+        );
+        assert_approx_ne!(
+            s.compute_solution(),
+            linear_system([e1, e2]).compute_solution()
+        );
+    }
+
+    #[test]
+    fn intersection_case() {
+        let line_1 = equation(vector([7.204, 3.182]), 8.68);
+        let line_2 = equation(vector([8.172, 4.114]), 9.883);
+        assert_approx_eq!(
+            line_1.intersect(&line_2),
+            Intersection::Some(vector([1.173, 0.073])),
+            Tolerance::Absolute(1e-3)
+        );
+        assert_approx_ne!(Intersection::None, Intersection::Infinity(line_1));
+    }
+}
@@ -1,7 +1,25 @@
 mod fmt;
 mod ops;
+pub(crate) mod parse;
 
-use crate::{math::first_nonzero_index, round::round_factory, vector, Vector};
+use crate::{
+    math::{self, first_nonzero_index_with, Tolerance},
+    round::round_factory,
+    vector, Vector,
+};
+
+pub(crate) const ZERO_NORMAL_VECTOR_HAS_NO_BASE_POINT: &str =
+    "Zero normal vector has no base point.";
+const ZERO_NORMAL_VECTOR_HAS_NO_NORMALIZE: &str = "Zero normal vector has no normalize.";
+
+/// Side of a hyperplane a point lies on, relative to the normal vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Positive,
+    Negative,
+    On,
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct Equation<const DIM: usize> {
@@ -16,7 +34,8 @@ pub fn equation<const DIM: usize>(normal_vector: Vector<DIM>, constant_term: f64
 
 impl<const DIM: usize> Equation<DIM> {
     fn new(normal_vector: Vector<DIM>, constant_term: f64) -> Self {
-        let base_point = Self::build_base_point(normal_vector, constant_term);
+        let base_point =
+            Self::build_base_point(normal_vector, constant_term, &Tolerance::default());
         Equation {
             normal_vector,
             constant_term,
@@ -24,8 +43,12 @@ impl<const DIM: usize> Equation<DIM> {
         }
     }
 
-    fn build_base_point(normal_vector: Vector<DIM>, constant_term: f64) -> Option<Vector<DIM>> {
-        match first_nonzero_index(normal_vector) {
+    fn build_base_point(
+        normal_vector: Vector<DIM>,
+        constant_term: f64,
+        tolerance: &Tolerance,
+    ) -> Option<Vector<DIM>> {
+        match first_nonzero_index_with(normal_vector, tolerance) {
             Ok(initial_index) => {
                 let mut base_point_coords = [0.; DIM];
                 let initial_coefficient = normal_vector[initial_index];
@@ -36,6 +59,10 @@ impl<const DIM: usize> Equation<DIM> {
         }
     }
 
+    pub fn base_point(&self) -> Option<Vector<DIM>> {
+        self.base_point
+    }
+
     pub fn round(&self, precision: usize) -> Self {
         let round = &round_factory(precision);
         let base_point = self.base_point.map(|base_point| base_point.map(round));
@@ -50,15 +77,85 @@ impl<const DIM: usize> Equation<DIM> {
         self.normal_vector.is_parallel(&other.normal_vector)
     }
 
+    /// Whether both equations describe the same hyperplane, deciding with `tolerance`.
+    pub fn eq_with(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        let self_base_point =
+            Self::build_base_point(self.normal_vector, self.constant_term, tolerance);
+        let other_base_point =
+            Self::build_base_point(other.normal_vector, other.constant_term, tolerance);
+        match (self_base_point, other_base_point) {
+            (Some(self_basepoint), Some(other_basepoint)) => {
+                let connect_vector = self_basepoint - other_basepoint;
+                let scale = self_basepoint.magnitude().max(other_basepoint.magnitude());
+                let is_orthogonal = |normal_vector: Vector<DIM>| {
+                    tolerance.is_negligible(
+                        connect_vector.dot(&normal_vector),
+                        scale * normal_vector.magnitude(),
+                    )
+                };
+                is_orthogonal(self.normal_vector) && is_orthogonal(other.normal_vector)
+            }
+            (None, None) => tolerance.approx_eq(self.constant_term, other.constant_term),
+            _ => false,
+        }
+    }
+
     pub fn scale(&self, scalar: f64) -> Self {
         equation(self.normal_vector * scalar, self.constant_term * scalar)
     }
+
+    /// Hessian normal form, a unit normal vector with the orientation kept.
+    pub fn normalize(&self) -> Result<Self, String> {
+        let magnitude = self.normal_vector.magnitude();
+        if math::is_zero(magnitude) {
+            return Err(String::from(ZERO_NORMAL_VECTOR_HAS_NO_NORMALIZE));
+        }
+        Ok(self.scale(1. / magnitude))
+    }
+
+    /// Positive on the side the normal vector points to.
+    pub fn signed_distance(&self, point: &Vector<DIM>) -> Result<f64, String> {
+        let hessian = self.normalize()?;
+        Ok(hessian.normal_vector.dot(point) - hessian.constant_term)
+    }
+
+    pub fn project_point(&self, point: &Vector<DIM>) -> Result<Vector<DIM>, String> {
+        let hessian = self.normalize()?;
+        let distance = hessian.normal_vector.dot(point) - hessian.constant_term;
+        Ok(*point - hessian.normal_vector * distance)
+    }
+
+    pub fn reflect_point(&self, point: &Vector<DIM>) -> Result<Vector<DIM>, String> {
+        let hessian = self.normalize()?;
+        let distance = hessian.normal_vector.dot(point) - hessian.constant_term;
+        Ok(*point - hessian.normal_vector * (2. * distance))
+    }
+
+    /// A zero normal vector contains every point when the constant term is zero, otherwise none.
+    pub fn contains(&self, point: &Vector<DIM>, tolerance: &Tolerance) -> bool {
+        match self.signed_distance(point) {
+            Ok(distance) => tolerance.is_negligible(distance, point.magnitude()),
+            Err(_) => tolerance.is_zero(self.constant_term),
+        }
+    }
+
+    pub fn side_of(&self, point: &Vector<DIM>) -> Side {
+        if self.contains(point, &Tolerance::default()) {
+            return Side::On;
+        }
+        if self.normal_vector.dot(point) > self.constant_term {
+            Side::Positive
+        } else {
+            Side::Negative
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        equation::{equation, Equation},
+        equation::{equation, Equation, Side},
+        math::Tolerance,
         vector,
     };
 
@@ -108,4 +205,75 @@ mod tests {
         assert_ne!(planes_1, planes_2);
         assert!(planes_1.is_parallel(&planes_2));
     }
+
+    #[test]
+    fn eq_with() {
+        let line_1 = equation(vector([1e-12, 2e-12]), 1e-12);
+        let line_2 = equation(vector([1e-12, 2e-12]), 3e-12);
+        assert_eq!(line_1, line_2);
+        assert!(!line_1.eq_with(&line_2, &Tolerance::Relative(1e-10)));
+        assert!(line_1.eq_with(&(line_1 * 7.), &Tolerance::Relative(1e-10)));
+
+        let line_1 = equation(vector([1., 2.]), 1e8);
+        let line_2 = equation(vector([1., 2.]), 1e8 + 1e-4);
+        assert_ne!(line_1, line_2);
+        assert!(line_1.eq_with(&line_2, &Tolerance::Relative(1e-10)));
+    }
+
+    #[test]
+    fn normalize() {
+        let plane = equation(vector([0., 3., 4.]), 10.);
+        assert_eq!(plane.normalize(), Ok(equation(vector([0., 0.6, 0.8]), 2.)));
+        assert!(equation(vector([0., 0.]), 1.).normalize().is_err());
+    }
+
+    #[test]
+    fn signed_distance() {
+        let line = equation(vector([3., 4.]), 5.);
+        assert_eq!(line.signed_distance(&vector([3., 4.])), Ok(4.));
+        assert_eq!(line.signed_distance(&vector([0., 0.])), Ok(-1.));
+        assert!(equation(vector([0., 0.]), 1.)
+            .signed_distance(&vector([0., 0.]))
+            .is_err());
+    }
+
+    #[test]
+    fn project_point() {
+        let plane = equation(vector([0., 0., 2.]), 2.);
+        assert_eq!(
+            plane.project_point(&vector([1., 2., 5.])),
+            Ok(vector([1., 2., 1.]))
+        );
+        assert_eq!(
+            plane.reflect_point(&vector([1., 2., 5.])),
+            Ok(vector([1., 2., -3.]))
+        );
+        assert!(equation(vector([0., 0.]), 1.)
+            .reflect_point(&vector([1., 1.]))
+            .is_err());
+    }
+
+    #[test]
+    fn side_of() {
+        let line = equation(vector([1., 1.]), 2.);
+        assert_eq!(line.side_of(&vector([2., 2.])), Side::Positive);
+        assert_eq!(line.side_of(&vector([0., 0.])), Side::Negative);
+        assert_eq!(line.side_of(&vector([1., 1.])), Side::On);
+        assert_eq!((line * -1.).side_of(&vector([2., 2.])), Side::Negative);
+    }
+
+    #[test]
+    fn contains() {
+        let line = equation(vector([1., 1.]), 2.);
+        assert!(line.contains(&vector([0.5, 1.5]), &Tolerance::default()));
+        assert!(!line.contains(&vector([0.5, 1.5001]), &Tolerance::default()));
+        assert!(line.contains(&vector([0.5, 1.5001]), &Tolerance::Absolute(1e-3)));
+        assert!(line.contains(
+            &vector([1e12, 2. - 1e12 + 1e-3]),
+            &Tolerance::Relative(1e-12)
+        ));
+
+        assert!(equation(vector([0., 0.]), 0.).contains(&vector([1., 1.]), &Tolerance::default()));
+        assert!(!equation(vector([0., 0.]), 1.).contains(&vector([1., 1.]), &Tolerance::default()));
+    }
 }
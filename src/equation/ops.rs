@@ -1,53 +1,69 @@
 use std::ops::{Add, Mul, Sub};
 
-use crate::{equation, math::eq};
+use crate::{equation, math::Tolerance};
 
 use super::Equation;
 
 impl<const DIM: usize> PartialEq for Equation<DIM> {
     fn eq(&self, other: &Self) -> bool {
-        match (self.base_point, other.base_point) {
-            (Some(self_basepoint), Some(other_basepoint)) => {
-                let connect_vector = self_basepoint - other_basepoint;
-                connect_vector.is_orthogonal(&self.normal_vector)
-                    && connect_vector.is_orthogonal(&other.normal_vector)
-            }
-            (None, None) => eq(self.constant_term, other.constant_term),
-            _ => false,
-        }
+        self.eq_with(other, &Tolerance::default())
     }
 }
 
-impl<const DIM: usize> Add for Equation<DIM> {
-    type Output = Self;
+impl<const DIM: usize> Add for &Equation<DIM> {
+    type Output = Equation<DIM>;
 
     fn add(self, rhs: Self) -> Self::Output {
         equation(
-            self.normal_vector + rhs.normal_vector,
+            Add::add(&self.normal_vector, &rhs.normal_vector),
             self.constant_term + rhs.constant_term,
         )
     }
 }
 
-impl<const DIM: usize> Sub for Equation<DIM> {
+impl<const DIM: usize> Add for Equation<DIM> {
     type Output = Self;
 
+    fn add(self, rhs: Self) -> Self::Output {
+        Add::add(&self, &rhs)
+    }
+}
+
+impl<const DIM: usize> Sub for &Equation<DIM> {
+    type Output = Equation<DIM>;
+
     fn sub(self, rhs: Self) -> Self::Output {
         equation(
-            self.normal_vector - rhs.normal_vector,
+            Sub::sub(&self.normal_vector, &rhs.normal_vector),
             self.constant_term - rhs.constant_term,
         )
     }
 }
 
-impl<const DIM: usize> Mul<f64> for Equation<DIM> {
+impl<const DIM: usize> Sub for Equation<DIM> {
     type Output = Self;
 
+    fn sub(self, rhs: Self) -> Self::Output {
+        Sub::sub(&self, &rhs)
+    }
+}
+
+impl<const DIM: usize> Mul<f64> for &Equation<DIM> {
+    type Output = Equation<DIM>;
+
     fn mul(self, rhs: f64) -> Self::Output {
         self.scale(rhs)
     }
 }
 
+impl<const DIM: usize> Mul<f64> for Equation<DIM> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{equation, vector};
@@ -97,4 +113,14 @@ mod tests {
         let e = equation(vector([0., 1.]), 3.);
         assert_eq!(e * 2., equation(vector([0., 2.]), 6.));
     }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference() {
+        let a = equation(vector([2., 3.]), 2.);
+        let b = equation(vector([1., -1.]), -5.);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * 2., a * 2.);
+    }
 }
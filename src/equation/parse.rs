@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{tokenize, ParseError, Parser, Token},
+    vector, Vector,
+};
+
+use super::{equation, Equation};
+
+/// Sum of terms `a_0 x_0 + ... + a_n x_n + c` on one side of `=`.
+struct Side<const DIM: usize> {
+    coefficients: [f64; DIM],
+    constant: f64,
+}
+
+impl Parser<'_> {
+    fn parse_side<const DIM: usize>(&mut self) -> Result<Side<DIM>, ParseError> {
+        let mut side = Side {
+            coefficients: [0.; DIM],
+            constant: 0.,
+        };
+        let mut is_initial_term = true;
+        loop {
+            let sign = match self.peek().token {
+                Token::Plus => {
+                    self.next();
+                    1.
+                }
+                Token::Minus => {
+                    self.next();
+                    -1.
+                }
+                _ if is_initial_term => 1.,
+                _ => return Ok(side),
+            };
+            self.parse_term(&mut side, sign)?;
+            is_initial_term = false;
+        }
+    }
+
+    /// `2`, `2x_1`, `2 * x_1` or `x_1`.
+    fn parse_term<const DIM: usize>(
+        &mut self,
+        side: &mut Side<DIM>,
+        sign: f64,
+    ) -> Result<(), ParseError> {
+        let start = self.next();
+        let (coefficient, variable) = match start.token {
+            Token::Number(number) => match self.peek().token {
+                Token::Star => {
+                    self.next();
+                    let variable = self.next();
+                    match variable.token {
+                        Token::Variable(index) => (number, Some((index, variable))),
+                        _ => return Err(variable.error("expected a variable")),
+                    }
+                }
+                Token::Variable(index) => (number, Some((index, self.next()))),
+                _ => (number, None),
+            },
+            Token::Variable(index) => (1., Some((index, start))),
+            _ => return Err(start.error("expected a term")),
+        };
+
+        match variable {
+            Some((index, variable)) => {
+                if index >= DIM {
+                    return Err(variable.error(format!(
+                        "variable x_{} out of range for dimension {}",
+                        index, DIM
+                    )));
+                }
+                side.coefficients[index] += sign * coefficient;
+            }
+            None => side.constant += sign * coefficient,
+        }
+        Ok(())
+    }
+}
+
+/// Parses `text` as if it started at `line` and `column` of a larger input.
+pub(crate) fn parse_equation<const DIM: usize>(
+    text: &str,
+    line: usize,
+    column: usize,
+) -> Result<Equation<DIM>, ParseError> {
+    let tokens = tokenize(text, line, column)?;
+    let mut parser = Parser::new(&tokens);
+    let left = parser.parse_side::<DIM>()?;
+    parser.expect(Token::Equals, "expected `=`")?;
+    let right = parser.parse_side::<DIM>()?;
+    parser.expect(Token::End, "expected `+`, `-` or the end of the equation")?;
+
+    let normal_vector: Vector<DIM> = vector(left.coefficients) - vector(right.coefficients);
+    Ok(equation(normal_vector, right.constant - left.constant))
+}
+
+impl<const DIM: usize> FromStr for Equation<DIM> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_equation(s, 1, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{equation, parse::ParseError, vector, Equation, Vector};
+
+    /// The coefficients and constant term, since `==` on `Equation` ignores scale.
+    fn terms<const DIM: usize>(s: &str) -> Result<(Vector<DIM>, f64), ParseError> {
+        s.parse::<Equation<DIM>>()
+            .map(|e| (e.normal_vector, e.constant_term))
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            terms("-0.12x_0 + 2.3x_1 = 5.4"),
+            Ok((vector([-0.12, 2.3]), 5.4))
+        );
+        assert_eq!(terms("5.6x_0 - x_1 = 0"), Ok((vector([5.6, -1.]), 0.)));
+        assert_eq!(terms("x + 2*y - z = 3"), Ok((vector([1., 2., -1.]), 3.)));
+        assert_eq!(terms("2x + 1 = y - 3 + x"), Ok((vector([1., -1.]), -4.)));
+        assert_eq!(terms("0 = 0"), Ok((vector([0., 0.]), 0.)));
+        assert_eq!(terms("2x = 4"), Ok((vector([2.]), 4.)));
+
+        let e = equation(vector([3.231, 0., -1e-3]), 2.519);
+        assert_eq!(
+            terms(&format!("{}", e)),
+            Ok((e.normal_vector, e.constant_term))
+        );
+    }
+
+    #[test]
+    fn from_str_error() {
+        let parse = |s: &str| s.parse::<Equation<3>>();
+        assert_eq!(parse("x + y"), Err(ParseError::new(1, 6, "expected `=`")));
+        assert_eq!(
+            parse("x + = 1"),
+            Err(ParseError::new(1, 5, "expected a term"))
+        );
+        assert_eq!(
+            parse("x_0 + x_3 = 1"),
+            Err(ParseError::new(
+                1,
+                7,
+                "variable x_3 out of range for dimension 3"
+            ))
+        );
+        assert_eq!(
+            parse("2 * 3 = 1"),
+            Err(ParseError::new(1, 5, "expected a variable"))
+        );
+        assert_eq!(
+            parse("x = 1 = 2"),
+            Err(ParseError::new(
+                1,
+                7,
+                "expected `+`, `-` or the end of the equation"
+            ))
+        );
+        assert_eq!(parse("x y = 2"), Err(ParseError::new(1, 3, "expected `=`")));
+    }
+}
@@ -0,0 +1,129 @@
+mod csv;
+mod matrix_market;
+mod npy;
+
+use std::fmt::Display;
+
+pub use csv::{CsvOptions, Delimiter, NanPolicy, Orientation};
+pub use matrix_market::{Field, Format, MatrixMarketHeader, Symmetry};
+pub use npy::{Dtype, NpyOptions, NpyVersion, Order};
+
+use crate::parse::ParseError;
+
+/// Failure to read or write a file format.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Header(String),
+    /// `(rows, columns)` of the target type and of the data.
+    Shape {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    Parse(ParseError),
+    /// A value the format or its options can't represent.
+    Value(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Header(message) => write!(f, "invalid header: {}", message),
+            Error::Shape { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::Value(message) => write!(f, "invalid value: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Whitespace separated fields of `line` with their columns, starting at 1.
+pub(crate) fn fields(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |field| {
+        let offset = field.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count() + 1, field)
+    })
+}
+
+pub(crate) fn parse_number<T: std::str::FromStr>(
+    field: &str,
+    line: usize,
+    column: usize,
+) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::new(line, column, format!("invalid number `{}`", field)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::ParseError;
+
+    use super::{fields, parse_number, Error};
+
+    #[test]
+    fn fields_case() {
+        let fields: Vec<_> = fields("  3 4\t12 ").collect();
+        assert_eq!(fields, vec![(3, "3"), (5, "4"), (7, "12")]);
+    }
+
+    #[test]
+    fn parse_number_case() {
+        assert_eq!(parse_number::<f64>("-1.5e2", 1, 1), Ok(-150.));
+        assert_eq!(
+            parse_number::<usize>("-1", 2, 3),
+            Err(ParseError::new(2, 3, "invalid number `-1`"))
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!(
+                "{}",
+                Error::Shape {
+                    expected: (2, 3),
+                    found: (3, 2)
+                }
+            ),
+            "expected a 2x3 matrix, found 3x2"
+        );
+        assert_eq!(
+            format!("{}", Error::Header(String::from("missing banner"))),
+            "invalid header: missing banner"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Error::from(ParseError::new(4, 1, "expected 3 values"))
+            ),
+            "line 4, column 1: expected 3 values"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::io::{BufRead, Write};
+
+use crate::{matrix, parse::ParseError, Matrix, Vector};
+
+use super::{fields, parse_number, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    Char(char),
+    /// Any run of whitespace when reading, a single space when writing.
+    Whitespace,
+}
+
+/// What to do with empty and `NaN` fields, and with `NaN` values when writing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum NanPolicy {
+    #[default]
+    Reject,
+    Keep,
+    Replace(f64),
+}
+
+/// Whether a vector is stored as a single row or as a single column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    #[default]
+    Row,
+    Column,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: Delimiter,
+    /// Lines skipped before the data when reading.
+    pub header_lines: usize,
+    pub nan_policy: NanPolicy,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: Delimiter::Char(','),
+            header_lines: 0,
+            nan_policy: NanPolicy::default(),
+        }
+    }
+}
+
+/// Fields of `line` with their columns, starting at 1.
+fn split(line: &str, delimiter: Delimiter) -> Vec<(usize, &str)> {
+    match delimiter {
+        Delimiter::Whitespace => fields(line).collect(),
+        Delimiter::Char(delimiter) => {
+            let mut column = 1;
+            line.split(delimiter)
+                .map(|field| {
+                    let start = column;
+                    column += field.chars().count() + 1;
+                    let trimmed = field.trim_start();
+                    let start = start + field.chars().count() - trimmed.chars().count();
+                    (start, trimmed.trim_end())
+                })
+                .collect()
+        }
+    }
+}
+
+fn parse_value(
+    field: &str,
+    line: usize,
+    column: usize,
+    nan_policy: NanPolicy,
+) -> Result<f64, ParseError> {
+    let is_missing = field.is_empty() || field.eq_ignore_ascii_case("nan");
+    match (is_missing, nan_policy) {
+        (false, _) => parse_number(field, line, column),
+        (true, NanPolicy::Reject) => Err(ParseError::new(line, column, "missing value")),
+        (true, NanPolicy::Keep) => Ok(f64::NAN),
+        (true, NanPolicy::Replace(value)) => Ok(value),
+    }
+}
+
+/// Rows of the data with the line each starts on, blank lines are skipped.
+fn read_rows(reader: impl BufRead, options: &CsvOptions) -> Result<Vec<(usize, Vec<f64>)>, Error> {
+    let mut rows = vec![];
+    for (index, line) in reader.lines().enumerate().skip(options.header_lines) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = split(&line, options.delimiter)
+            .into_iter()
+            .map(|(column, field)| parse_value(field, index + 1, column, options.nan_policy))
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.push((index + 1, row));
+    }
+    Ok(rows)
+}
+
+fn write_rows<'a>(
+    mut writer: impl Write,
+    rows: impl IntoIterator<Item = &'a [f64]>,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    let delimiter = match options.delimiter {
+        Delimiter::Char(delimiter) => delimiter,
+        Delimiter::Whitespace => ' ',
+    };
+    let rows: Vec<Vec<f64>> = rows
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .map(|&value| match (value.is_nan(), options.nan_policy) {
+                    (true, NanPolicy::Reject) => Err(Error::Value(String::from(
+                        "NaN is rejected by the NaN policy",
+                    ))),
+                    (true, NanPolicy::Replace(replacement)) => Ok(replacement),
+                    _ => Ok(value),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    for row in rows {
+        for (index, value) in row.into_iter().enumerate() {
+            if index != 0 {
+                write!(writer, "{}", delimiter)?;
+            }
+            write!(writer, "{}", value)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn from_csv(reader: impl BufRead, options: &CsvOptions) -> Result<Self, Error> {
+        let rows = read_rows(reader, options)?;
+        let cols = rows.first().map_or(0, |(_, row)| row.len());
+        if (rows.len(), cols) != (ROW, COL) {
+            return Err(Error::Shape {
+                expected: (ROW, COL),
+                found: (rows.len(), cols),
+            });
+        }
+
+        let mut values = [[0.; COL]; ROW];
+        for (values, (line, row)) in values.iter_mut().zip(rows) {
+            let len = row.len();
+            *values = row.try_into().map_err(|_| {
+                ParseError::new(line, 1, format!("expected {} fields, found {}", COL, len))
+            })?;
+        }
+        Ok(matrix(values))
+    }
+
+    pub fn to_csv(&self, writer: impl Write, options: &CsvOptions) -> Result<(), Error> {
+        let rows: Vec<[f64; COL]> = self
+            .into_iter()
+            .map(|row| std::array::from_fn(|col| row[col]))
+            .collect();
+        write_rows(writer, rows.iter().map(|row| &row[..]), options)
+    }
+}
+
+impl<const DIM: usize> Vector<DIM> {
+    pub fn from_csv(
+        reader: impl BufRead,
+        options: &CsvOptions,
+        orientation: Orientation,
+    ) -> Result<Self, Error> {
+        Ok(match orientation {
+            Orientation::Row => Matrix::<1, DIM>::from_csv(reader, options)?.get_row(0),
+            Orientation::Column => Matrix::<DIM, 1>::from_csv(reader, options)?.get_col(0),
+        })
+    }
+
+    pub fn to_csv(
+        &self,
+        writer: impl Write,
+        options: &CsvOptions,
+        orientation: Orientation,
+    ) -> Result<(), Error> {
+        let values: [f64; DIM] = std::array::from_fn(|index| self[index]);
+        match orientation {
+            Orientation::Row => write_rows(writer, [&values[..]], options),
+            Orientation::Column => write_rows(writer, values.chunks(1), options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{io::Error, matrix, parse::ParseError, vector, Matrix, Vector};
+
+    use super::{split, CsvOptions, Delimiter, NanPolicy, Orientation};
+
+    #[test]
+    fn split_case() {
+        assert_eq!(
+            split("1, 2 ,,3", Delimiter::Char(',')),
+            vec![(1, "1"), (4, "2"), (7, ""), (8, "3")]
+        );
+        assert_eq!(
+            split(" 1\t 2", Delimiter::Whitespace),
+            vec![(2, "1"), (5, "2")]
+        );
+    }
+
+    #[test]
+    fn from_csv() {
+        let m = matrix([[1., 2.5, -3.], [4., 5., 6e-3]]);
+        let text = "1,2.5,-3\n4, 5, 6e-3\n\n";
+        assert_eq!(
+            Matrix::from_csv(text.as_bytes(), &CsvOptions::default()).unwrap(),
+            m
+        );
+
+        let options = CsvOptions {
+            delimiter: Delimiter::Whitespace,
+            header_lines: 2,
+            ..Default::default()
+        };
+        let text = "# measurements\na b c\n1 2.5 -3\n  4 5\t6e-3\n";
+        assert_eq!(Matrix::from_csv(text.as_bytes(), &options).unwrap(), m);
+
+        let options = CsvOptions {
+            delimiter: Delimiter::Char(';'),
+            nan_policy: NanPolicy::Replace(0.),
+            ..Default::default()
+        };
+        assert_eq!(
+            Matrix::from_csv("1;;NaN".as_bytes(), &options).unwrap(),
+            matrix([[1., 0., 0.]])
+        );
+
+        let options = CsvOptions {
+            nan_policy: NanPolicy::Keep,
+            ..Default::default()
+        };
+        let m: Matrix<1, 2> = Matrix::from_csv("nan,1".as_bytes(), &options).unwrap();
+        assert!(m[0][0].is_nan());
+
+        let v = vector([1., 2., 3.]);
+        let options = CsvOptions::default();
+        assert_eq!(
+            Vector::from_csv("1,2,3".as_bytes(), &options, Orientation::Row).unwrap(),
+            v
+        );
+        assert_eq!(
+            Vector::from_csv("1\n2\n3\n".as_bytes(), &options, Orientation::Column).unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn from_csv_error() {
+        let read = |text: &str| Matrix::<2, 2>::from_csv(text.as_bytes(), &CsvOptions::default());
+        assert!(matches!(
+            read("1,2,3\n4,5,6\n"),
+            Err(Error::Shape {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        ));
+        assert!(matches!(
+            read("1,2\n"),
+            Err(Error::Shape {
+                expected: (2, 2),
+                found: (1, 2)
+            })
+        ));
+        assert!(matches!(
+            read("1,2\n3,4,5\n"),
+            Err(Error::Parse(error)) if error == ParseError::new(2, 1, "expected 2 fields, found 3")
+        ));
+        assert!(matches!(
+            read("1,2\n3,\n"),
+            Err(Error::Parse(error)) if error == ParseError::new(2, 3, "missing value")
+        ));
+        assert!(matches!(
+            read("1,2\n3,x\n"),
+            Err(Error::Parse(error)) if error == ParseError::new(2, 3, "invalid number `x`")
+        ));
+        assert!(matches!(
+            Vector::<3>::from_csv(
+                "1,2,3".as_bytes(),
+                &CsvOptions::default(),
+                Orientation::Column
+            ),
+            Err(Error::Shape {
+                expected: (3, 1),
+                found: (1, 3)
+            })
+        ));
+    }
+
+    #[test]
+    fn to_csv() {
+        let write = |m: &Matrix<2, 2>, options: &CsvOptions| {
+            let mut buffer = vec![];
+            m.to_csv(&mut buffer, options)
+                .map(|_| String::from_utf8(buffer).unwrap())
+        };
+        let m = matrix([[1., 2.5], [-3., f64::NAN]]);
+        assert!(matches!(
+            write(&m, &CsvOptions::default()),
+            Err(Error::Value(_))
+        ));
+        let options = CsvOptions {
+            nan_policy: NanPolicy::Keep,
+            ..Default::default()
+        };
+        assert_eq!(write(&m, &options).unwrap(), "1,2.5\n-3,NaN\n");
+        let options = CsvOptions {
+            delimiter: Delimiter::Whitespace,
+            nan_policy: NanPolicy::Replace(0.),
+            ..Default::default()
+        };
+        assert_eq!(write(&m, &options).unwrap(), "1 2.5\n-3 0\n");
+
+        let v = vector([1., 2., 3.]);
+        let mut buffer = vec![];
+        v.to_csv(&mut buffer, &CsvOptions::default(), Orientation::Column)
+            .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1\n2\n3\n");
+        let mut buffer = vec![];
+        v.to_csv(&mut buffer, &CsvOptions::default(), Orientation::Row)
+            .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1,2,3\n");
+    }
+}
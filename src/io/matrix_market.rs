@@ -0,0 +1,439 @@
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+};
+
+use crate::{matrix, parse::ParseError, Matrix};
+
+use super::{fields, parse_number, Error};
+
+const BANNER: &str = "%%MatrixMarket";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Only the nonzero entries, as `row col value`.
+    Coordinate,
+    /// Every entry in column-major order.
+    #[default]
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Field {
+    #[default]
+    Real,
+    Integer,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Symmetry {
+    #[default]
+    General,
+    /// Only the lower triangle is stored.
+    Symmetric,
+}
+
+/// The `%%MatrixMarket matrix <format> <field> <symmetry>` banner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MatrixMarketHeader {
+    pub format: Format,
+    pub field: Field,
+    pub symmetry: Symmetry,
+}
+
+impl MatrixMarketHeader {
+    fn parse(line: &str) -> Result<Self, Error> {
+        let words: Vec<String> = line.split_whitespace().map(str::to_lowercase).collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let [banner, object, format, field, symmetry] = words[..] else {
+            return Err(Error::Header(format!(
+                "expected `{} matrix <format> <field> <symmetry>`",
+                BANNER
+            )));
+        };
+        if banner != BANNER.to_lowercase() || object != "matrix" {
+            return Err(Error::Header(format!(
+                "expected `{} matrix`, found `{} {}`",
+                BANNER, banner, object
+            )));
+        }
+
+        let unsupported = |kind: &str, value: &str| {
+            Err(Error::Header(format!("unsupported {} `{}`", kind, value)))
+        };
+        let format = match format {
+            "coordinate" => Format::Coordinate,
+            "array" => Format::Array,
+            _ => return unsupported("format", format),
+        };
+        let field = match field {
+            "real" => Field::Real,
+            "integer" => Field::Integer,
+            _ => return unsupported("field", field),
+        };
+        let symmetry = match symmetry {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            _ => return unsupported("symmetry", symmetry),
+        };
+        Ok(MatrixMarketHeader {
+            format,
+            field,
+            symmetry,
+        })
+    }
+}
+
+impl Display for MatrixMarketHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self.format {
+            Format::Coordinate => "coordinate",
+            Format::Array => "array",
+        };
+        let field = match self.field {
+            Field::Real => "real",
+            Field::Integer => "integer",
+        };
+        let symmetry = match self.symmetry {
+            Symmetry::General => "general",
+            Symmetry::Symmetric => "symmetric",
+        };
+        write!(f, "{} matrix {} {} {}", BANNER, format, field, symmetry)
+    }
+}
+
+/// A field with its line and column.
+type Located = (usize, usize, String);
+
+/// Fields of the non-comment lines after the banner, with their line and column.
+fn data_fields(
+    lines: impl Iterator<Item = std::io::Result<String>>,
+) -> Result<Vec<Vec<Located>>, Error> {
+    let mut data = vec![];
+    for (index, line) in lines.enumerate() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+        data.push(
+            fields(&line)
+                .map(|(column, field)| (index + 2, column, String::from(field)))
+                .collect(),
+        );
+    }
+    Ok(data)
+}
+
+fn parse_value(field: &Located, header: &MatrixMarketHeader) -> Result<f64, Error> {
+    let (line, column, value) = field;
+    Ok(match header.field {
+        Field::Real => parse_number(value, *line, *column)?,
+        Field::Integer => parse_number::<i64>(value, *line, *column)? as f64,
+    })
+}
+
+fn parse_index(field: &Located, len: usize) -> Result<usize, Error> {
+    let (line, column, value) = field;
+    let index: usize = parse_number(value, *line, *column)?;
+    if index == 0 || index > len {
+        return Err(ParseError::new(
+            *line,
+            *column,
+            format!("index {} out of range 1..={}", index, len),
+        )
+        .into());
+    }
+    Ok(index - 1)
+}
+
+impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn read_matrix_market(reader: impl BufRead) -> Result<Self, Error> {
+        let mut lines = reader.lines();
+        let banner = lines
+            .next()
+            .transpose()?
+            .ok_or_else(|| Error::Header(String::from("empty input")))?;
+        let header = MatrixMarketHeader::parse(&banner)?;
+
+        let data = data_fields(lines)?;
+        let Some((size, entries)) = data.split_first() else {
+            return Err(Error::Header(String::from("missing size line")));
+        };
+        let size_len = match header.format {
+            Format::Coordinate => 3,
+            Format::Array => 2,
+        };
+        if size.len() != size_len {
+            let (line, column, _) = size[0];
+            let message = format!("expected {} sizes, found {}", size_len, size.len());
+            return Err(ParseError::new(line, column, message).into());
+        }
+        let sizes = size
+            .iter()
+            .map(|(line, column, value)| parse_number::<usize>(value, *line, *column))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (rows, cols) = (sizes[0], sizes[1]);
+        if (rows, cols) != (ROW, COL) {
+            return Err(Error::Shape {
+                expected: (ROW, COL),
+                found: (rows, cols),
+            });
+        }
+        if header.symmetry == Symmetry::Symmetric && rows != cols {
+            return Err(Error::Header(String::from(
+                "symmetric matrix must be square",
+            )));
+        }
+
+        let mut values = [[0.; COL]; ROW];
+        match header.format {
+            Format::Coordinate => {
+                if entries.len() != sizes[2] {
+                    let (line, column, _) = &size[2];
+                    let message = format!("expected {} entries, found {}", sizes[2], entries.len());
+                    return Err(ParseError::new(*line, *column, message).into());
+                }
+                for entry in entries {
+                    let [row, col, value] = &entry[..] else {
+                        let (line, column, _) = &entry[0];
+                        let message = format!("expected 3 fields, found {}", entry.len());
+                        return Err(ParseError::new(*line, *column, message).into());
+                    };
+                    let (row, col) = (parse_index(row, ROW)?, parse_index(col, COL)?);
+                    let value = parse_value(value, &header)?;
+                    values[row][col] = value;
+                    if header.symmetry == Symmetry::Symmetric {
+                        values[col][row] = value;
+                    }
+                }
+            }
+            Format::Array => {
+                let positions: Vec<(usize, usize)> = match header.symmetry {
+                    Symmetry::General => (0..COL)
+                        .flat_map(|col| (0..ROW).map(move |row| (row, col)))
+                        .collect(),
+                    Symmetry::Symmetric => (0..COL)
+                        .flat_map(|col| (col..ROW).map(move |row| (row, col)))
+                        .collect(),
+                };
+                let fields: Vec<_> = entries.iter().flatten().collect();
+                if fields.len() != positions.len() {
+                    let (line, column, _) = &size[0];
+                    let message = format!(
+                        "expected {} values, found {}",
+                        positions.len(),
+                        fields.len()
+                    );
+                    return Err(ParseError::new(*line, *column, message).into());
+                }
+                for ((row, col), field) in positions.into_iter().zip(fields) {
+                    let value = parse_value(field, &header)?;
+                    values[row][col] = value;
+                    if header.symmetry == Symmetry::Symmetric {
+                        values[col][row] = value;
+                    }
+                }
+            }
+        }
+        Ok(matrix(values))
+    }
+
+    /// Fails with `Error::Header` when the matrix doesn't fit the header, e.g.
+    /// an unsymmetric matrix with `Symmetry::Symmetric`.
+    pub fn write_matrix_market(
+        &self,
+        mut writer: impl Write,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), Error> {
+        if header.symmetry == Symmetry::Symmetric && !self.is_symmetric() {
+            return Err(Error::Header(String::from("matrix is not symmetric")));
+        }
+        if header.field == Field::Integer && self.into_iter().flatten().any(|x| x.fract() != 0.) {
+            return Err(Error::Header(String::from("matrix has non-integer values")));
+        }
+
+        let positions: Vec<(usize, usize)> = (0..COL)
+            .flat_map(|col| (0..ROW).map(move |row| (row, col)))
+            .filter(|&(row, col)| header.symmetry == Symmetry::General || row >= col)
+            .collect();
+        let write_value = |writer: &mut dyn Write, value: f64| match header.field {
+            Field::Real => write!(writer, "{}", value),
+            Field::Integer => write!(writer, "{}", value as i64),
+        };
+
+        writeln!(writer, "{}", header)?;
+        match header.format {
+            Format::Coordinate => {
+                let entries: Vec<_> = positions
+                    .into_iter()
+                    .filter(|&(row, col)| self[row][col] != 0.)
+                    .collect();
+                writeln!(writer, "{} {} {}", ROW, COL, entries.len())?;
+                for (row, col) in entries {
+                    write!(writer, "{} {} ", row + 1, col + 1)?;
+                    write_value(&mut writer, self[row][col])?;
+                    writeln!(writer)?;
+                }
+            }
+            Format::Array => {
+                writeln!(writer, "{} {}", ROW, COL)?;
+                for (row, col) in positions {
+                    write_value(&mut writer, self[row][col])?;
+                    writeln!(writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_symmetric(&self) -> bool {
+        ROW == COL && (0..ROW).all(|row| (0..row).all(|col| self[row][col] == self[col][row]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{io::Error, matrix, parse::ParseError, Matrix};
+
+    use super::{Field, Format, MatrixMarketHeader, Symmetry};
+
+    fn read<const ROW: usize, const COL: usize>(text: &str) -> Result<Matrix<ROW, COL>, Error> {
+        Matrix::read_matrix_market(text.as_bytes())
+    }
+
+    fn write<const ROW: usize, const COL: usize>(
+        m: &Matrix<ROW, COL>,
+        header: &MatrixMarketHeader,
+    ) -> String {
+        let mut buffer = vec![];
+        m.write_matrix_market(&mut buffer, header).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn read_matrix_market() {
+        let m = matrix([[1., 0., 3.], [0., -2.5, 0.]]);
+        let text = "%%MatrixMarket matrix coordinate real general\n\
+                    % a comment\n\
+                    2 3 3\n\
+                    1 1 1.0\n\
+                    2 2 -2.5\n\
+                    1 3 3\n";
+        assert_eq!(read(text).unwrap(), m);
+
+        let text = "%%MatrixMarket matrix array real general\n2 3\n1\n0\n0\n-2.5\n3\n0\n";
+        assert_eq!(read(text).unwrap(), m);
+
+        let m = matrix([[1., 2.], [2., 3.]]);
+        let text =
+            "%%MatrixMarket matrix coordinate integer symmetric\n2 2 3\n1 1 1\n2 1 2\n2 2 3\n";
+        assert_eq!(read(text).unwrap(), m);
+        let text = "%%MATRIXMARKET Matrix Array Integer Symmetric\n2 2\n1\n2\n3\n";
+        assert_eq!(read(text).unwrap(), m);
+    }
+
+    #[test]
+    fn read_matrix_market_error() {
+        let header_error = |text: &str| match read::<2, 2>(text) {
+            Err(Error::Header(message)) => message,
+            other => panic!("expected a header error, found {:?}", other),
+        };
+        assert_eq!(header_error(""), "empty input");
+        assert_eq!(
+            header_error("%%MatrixMarket matrix coordinate pattern general\n"),
+            "unsupported field `pattern`"
+        );
+        assert_eq!(
+            header_error("%%MatrixMarket vector array real general\n"),
+            "expected `%%MatrixMarket matrix`, found `%%matrixmarket vector`"
+        );
+        assert_eq!(
+            header_error("%%MatrixMarket matrix array real general\n"),
+            "missing size line"
+        );
+
+        assert!(matches!(
+            read::<2, 2>("%%MatrixMarket matrix array real general\n3 2\n"),
+            Err(Error::Shape {
+                expected: (2, 2),
+                found: (3, 2)
+            })
+        ));
+
+        let parse_error = |text: &str| match read::<2, 2>(text) {
+            Err(Error::Parse(error)) => error,
+            other => panic!("expected a parse error, found {:?}", other),
+        };
+        assert_eq!(
+            parse_error("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n"),
+            ParseError::new(3, 1, "index 3 out of range 1..=2")
+        );
+        assert_eq!(
+            parse_error("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n"),
+            ParseError::new(2, 5, "expected 2 entries, found 1")
+        );
+        assert_eq!(
+            parse_error("%%MatrixMarket matrix array integer general\n2 2\n1\n2\n3.5\n4\n"),
+            ParseError::new(5, 1, "invalid number `3.5`")
+        );
+        assert_eq!(
+            parse_error("%%MatrixMarket matrix array real general\n2 2\n1 2 3\n"),
+            ParseError::new(2, 1, "expected 4 values, found 3")
+        );
+    }
+
+    #[test]
+    fn write_matrix_market() {
+        let m = matrix([[1., 0.], [0.5, -2.]]);
+        let header = MatrixMarketHeader::default();
+        assert_eq!(
+            write(&m, &header),
+            "%%MatrixMarket matrix array real general\n2 2\n1\n0.5\n0\n-2\n"
+        );
+        let header = MatrixMarketHeader {
+            format: Format::Coordinate,
+            ..Default::default()
+        };
+        let text = write(&m, &header);
+        assert_eq!(
+            text,
+            "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 1 1\n2 1 0.5\n2 2 -2\n"
+        );
+        assert_eq!(read(&text).unwrap(), m);
+
+        let m = matrix([[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]]);
+        let header = MatrixMarketHeader {
+            format: Format::Coordinate,
+            field: Field::Integer,
+            symmetry: Symmetry::Symmetric,
+        };
+        let text = write(&m, &header);
+        assert_eq!(
+            text,
+            "%%MatrixMarket matrix coordinate integer symmetric\n3 3 5\n1 1 4\n2 1 1\n2 2 3\n3 2 1\n3 3 2\n"
+        );
+        assert_eq!(read(&text).unwrap(), m);
+
+        let header = MatrixMarketHeader {
+            symmetry: Symmetry::Symmetric,
+            ..Default::default()
+        };
+        assert_eq!(read(&write(&m, &header)).unwrap(), m);
+
+        let mut buffer = vec![];
+        assert!(matches!(
+            matrix([[1., 2.], [3., 4.]]).write_matrix_market(&mut buffer, &header),
+            Err(Error::Header(_))
+        ));
+        let header = MatrixMarketHeader {
+            field: Field::Integer,
+            ..Default::default()
+        };
+        assert!(matches!(
+            matrix([[1.5]]).write_matrix_market(&mut buffer, &header),
+            Err(Error::Header(_))
+        ));
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,460 @@
+use std::io::{Read, Write};
+
+use crate::{matrix, Matrix, Vector};
+
+use super::Error;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// The whole header, magic string included, is padded to a multiple of this.
+const ALIGNMENT: usize = 64;
+/// Longest header read, numpy's own default limit. The stored length is
+/// untrusted and would otherwise size the allocation.
+const MAX_HEADER_LEN: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Dtype {
+    /// `<f8`
+    #[default]
+    F8,
+    /// `<f4`, values are rounded to `f32` when writing.
+    F4,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Order {
+    /// Row-major.
+    #[default]
+    C,
+    /// Column-major.
+    Fortran,
+}
+
+/// Version 1.0 stores the header length in 2 bytes, version 2.0 in 4.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NpyVersion {
+    #[default]
+    V1,
+    V2,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NpyOptions {
+    pub dtype: Dtype,
+    pub order: Order,
+    pub version: NpyVersion,
+}
+
+struct Header {
+    dtype: Dtype,
+    order: Order,
+    shape: Vec<usize>,
+}
+
+/// Value of `'key':` in the header dictionary, up to the next top-level comma.
+fn dict_value<'a>(dict: &'a str, key: &str) -> Result<&'a str, Error> {
+    let pattern = format!("'{}':", key);
+    let start = dict
+        .find(&pattern)
+        .ok_or_else(|| Error::Header(format!("missing key `{}`", key)))?
+        + pattern.len();
+    let rest = dict[start..].trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|end| end + 1)
+    } else {
+        rest.find([',', '}'])
+    };
+    Ok(rest[..end.unwrap_or(rest.len())].trim())
+}
+
+impl Header {
+    fn parse(dict: &str) -> Result<Self, Error> {
+        let dtype = match dict_value(dict, "descr")? {
+            "'<f8'" => Dtype::F8,
+            "'<f4'" => Dtype::F4,
+            descr => return Err(Error::Header(format!("unsupported dtype {}", descr))),
+        };
+        let order = match dict_value(dict, "fortran_order")? {
+            "False" => Order::C,
+            "True" => Order::Fortran,
+            value => return Err(Error::Header(format!("invalid fortran_order `{}`", value))),
+        };
+        let shape = dict_value(dict, "shape")?;
+        let shape = shape
+            .strip_prefix('(')
+            .and_then(|shape| shape.strip_suffix(')'))
+            .ok_or_else(|| Error::Header(format!("invalid shape `{}`", shape)))?
+            .split(',')
+            .map(str::trim)
+            .filter(|size| !size.is_empty())
+            .map(|size| {
+                size.parse()
+                    .map_err(|_| Error::Header(format!("invalid size `{}`", size)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Header {
+            dtype,
+            order,
+            shape,
+        })
+    }
+
+    fn read(reader: &mut impl Read) -> Result<Self, Error> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic[..6] != MAGIC {
+            return Err(Error::Header(String::from("missing magic string")));
+        }
+        let len = match magic[6] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            major => {
+                return Err(Error::Header(format!(
+                    "unsupported version {}.{}",
+                    major, magic[7]
+                )))
+            }
+        };
+        if len > MAX_HEADER_LEN {
+            return Err(Error::Header(format!(
+                "header length {} exceeds {}",
+                len, MAX_HEADER_LEN
+            )));
+        }
+        let mut dict = vec![0; len];
+        reader.read_exact(&mut dict)?;
+        let dict = String::from_utf8(dict)
+            .map_err(|_| Error::Header(String::from("header is not valid text")))?;
+        Self::parse(&dict)
+    }
+
+    fn write(&self, writer: &mut impl Write, version: NpyVersion) -> Result<(), Error> {
+        let descr = match self.dtype {
+            Dtype::F8 => "<f8",
+            Dtype::F4 => "<f4",
+        };
+        let fortran_order = match self.order {
+            Order::C => "False",
+            Order::Fortran => "True",
+        };
+        let shape = match self.shape[..] {
+            [len] => format!("({},)", len),
+            _ => {
+                let sizes: Vec<String> = self.shape.iter().map(usize::to_string).collect();
+                format!("({})", sizes.join(", "))
+            }
+        };
+        let mut dict = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            descr, fortran_order, shape
+        );
+
+        let prefix_len = match version {
+            NpyVersion::V1 => MAGIC.len() + 4,
+            NpyVersion::V2 => MAGIC.len() + 6,
+        };
+        let padding = (ALIGNMENT - (prefix_len + dict.len() + 1) % ALIGNMENT) % ALIGNMENT;
+        dict.push_str(&" ".repeat(padding));
+        dict.push('\n');
+
+        writer.write_all(MAGIC)?;
+        match version {
+            NpyVersion::V1 => {
+                writer.write_all(&[1, 0])?;
+                writer.write_all(&(dict.len() as u16).to_le_bytes())?;
+            }
+            NpyVersion::V2 => {
+                writer.write_all(&[2, 0])?;
+                writer.write_all(&(dict.len() as u32).to_le_bytes())?;
+            }
+        }
+        writer.write_all(dict.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// `(rows, cols)` of a 1 or 2 dimensional shape, a 1 dimensional array being a row.
+fn rows_and_cols(shape: &[usize]) -> Result<(usize, usize), Error> {
+    match *shape {
+        [len] => Ok((1, len)),
+        [rows, cols] => Ok((rows, cols)),
+        _ => Err(Error::Header(format!(
+            "expected a 1 or 2 dimensional array, found {} dimensions",
+            shape.len()
+        ))),
+    }
+}
+
+fn read_values(reader: &mut impl Read, dtype: Dtype, len: usize) -> Result<Vec<f64>, Error> {
+    let size = match dtype {
+        Dtype::F8 => 8,
+        Dtype::F4 => 4,
+    };
+    let mut bytes = vec![0; len * size];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes
+        .chunks_exact(size)
+        .map(|chunk| match dtype {
+            Dtype::F8 => f64::from_le_bytes(chunk.try_into().unwrap()),
+            Dtype::F4 => f32::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        })
+        .collect())
+}
+
+fn write_values(
+    writer: &mut impl Write,
+    dtype: Dtype,
+    values: impl Iterator<Item = f64>,
+) -> Result<(), Error> {
+    for value in values {
+        match dtype {
+            Dtype::F8 => writer.write_all(&value.to_le_bytes())?,
+            Dtype::F4 => writer.write_all(&(value as f32).to_le_bytes())?,
+        }
+    }
+    Ok(())
+}
+
+impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn read_npy(mut reader: impl Read) -> Result<Self, Error> {
+        let header = Header::read(&mut reader)?;
+        let shape = rows_and_cols(&header.shape)?;
+        if shape != (ROW, COL) {
+            return Err(Error::Shape {
+                expected: (ROW, COL),
+                found: shape,
+            });
+        }
+
+        let values = read_values(&mut reader, header.dtype, ROW * COL)?;
+        let mut rows = [[0.; COL]; ROW];
+        for (index, value) in values.into_iter().enumerate() {
+            let (row, col) = match header.order {
+                Order::C => (index / COL, index % COL),
+                Order::Fortran => (index % ROW, index / ROW),
+            };
+            rows[row][col] = value;
+        }
+        Ok(matrix(rows))
+    }
+
+    pub fn write_npy(&self, mut writer: impl Write, options: &NpyOptions) -> Result<(), Error> {
+        let header = Header {
+            dtype: options.dtype,
+            order: options.order,
+            shape: vec![ROW, COL],
+        };
+        header.write(&mut writer, options.version)?;
+        let values: Vec<f64> = match options.order {
+            Order::C => self.into_iter().flatten().collect(),
+            Order::Fortran => self.transpose().into_iter().flatten().collect(),
+        };
+        write_values(&mut writer, options.dtype, values.into_iter())
+    }
+}
+
+impl<const DIM: usize> Vector<DIM> {
+    /// Accepts the shapes `(DIM,)`, `(1, DIM)` and `(DIM, 1)`.
+    pub fn read_npy(mut reader: impl Read) -> Result<Self, Error> {
+        let header = Header::read(&mut reader)?;
+        let shape = rows_and_cols(&header.shape)?;
+        if shape != (1, DIM) && shape != (DIM, 1) {
+            return Err(Error::Shape {
+                expected: (1, DIM),
+                found: shape,
+            });
+        }
+        let values = read_values(&mut reader, header.dtype, DIM)?;
+        Ok(values.into_iter().collect())
+    }
+
+    /// Writes the shape `(DIM,)`, the order only affects the header.
+    pub fn write_npy(&self, mut writer: impl Write, options: &NpyOptions) -> Result<(), Error> {
+        let header = Header {
+            dtype: options.dtype,
+            order: options.order,
+            shape: vec![DIM],
+        };
+        header.write(&mut writer, options.version)?;
+        write_values(&mut writer, options.dtype, self.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{io::Error, matrix, vector, Matrix, Vector};
+
+    use super::{Dtype, NpyOptions, NpyVersion, Order};
+
+    /// Bytes of `numpy.save` with a version 1.0 header.
+    fn npy(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let padding = (64 - (10 + dict.len() + 1) % 64) % 64;
+        let dict = format!("{}{}\n", dict, " ".repeat(padding));
+        bytes.extend((dict.len() as u16).to_le_bytes());
+        bytes.extend(dict.as_bytes());
+        bytes.extend(data);
+        bytes
+    }
+
+    fn f8(values: &[f64]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn read_npy() {
+        let m = matrix([[1., 2., 3.], [4., 5., 6.]]);
+        let bytes = npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+            &f8(&[1., 2., 3., 4., 5., 6.]),
+        );
+        assert_eq!(Matrix::read_npy(&bytes[..]).unwrap(), m);
+
+        let bytes = npy(
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+            &f8(&[1., 4., 2., 5., 3., 6.]),
+        );
+        assert_eq!(Matrix::read_npy(&bytes[..]).unwrap(), m);
+
+        let data: Vec<u8> = [1.5f32, -2., 3.]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        let bytes = npy(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }",
+            &data,
+        );
+        assert_eq!(
+            Vector::read_npy(&bytes[..]).unwrap(),
+            vector([1.5, -2., 3.])
+        );
+        let bytes = npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (3, 1), }",
+            &f8(&[1., 2., 3.]),
+        );
+        assert_eq!(Vector::read_npy(&bytes[..]).unwrap(), vector([1., 2., 3.]));
+    }
+
+    #[test]
+    fn read_npy_error() {
+        let read = |bytes: Vec<u8>| Matrix::<2, 2>::read_npy(&bytes[..]);
+        let header_error = |bytes: Vec<u8>| match read(bytes) {
+            Err(Error::Header(message)) => message,
+            other => panic!("expected a header error, found {:?}", other),
+        };
+        assert_eq!(
+            header_error(b"\x93NUMPZ\x01\x00".to_vec()),
+            "missing magic string"
+        );
+        assert_eq!(
+            header_error(npy(
+                "{'descr': '>f8', 'fortran_order': False, 'shape': (2, 2), }",
+                &[]
+            )),
+            "unsupported dtype '>f8'"
+        );
+        assert_eq!(
+            header_error(b"\x93NUMPY\x02\x00\xff\xff\xff\xff".to_vec()),
+            "header length 4294967295 exceeds 10000"
+        );
+        assert_eq!(
+            header_error(npy("{'descr': '<f8', 'shape': (2, 2), }", &[])),
+            "missing key `fortran_order`"
+        );
+        assert_eq!(
+            header_error(npy(
+                "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2, 1), }",
+                &[]
+            )),
+            "expected a 1 or 2 dimensional array, found 3 dimensions"
+        );
+
+        assert!(matches!(
+            read(npy(
+                "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+                &f8(&[0.; 6])
+            )),
+            Err(Error::Shape {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        ));
+        assert!(matches!(
+            read(npy(
+                "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }",
+                &f8(&[0.; 3])
+            )),
+            Err(Error::Io(_))
+        ));
+        assert!(matches!(
+            Vector::<3>::read_npy(
+                &npy(
+                    "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }",
+                    &f8(&[0.; 2])
+                )[..]
+            ),
+            Err(Error::Shape {
+                expected: (1, 3),
+                found: (1, 2)
+            })
+        ));
+    }
+
+    #[test]
+    fn write_npy() {
+        let m = matrix([[1., 2., 3.], [4., 5., 6.]]);
+        let mut bytes = vec![];
+        m.write_npy(&mut bytes, &NpyOptions::default()).unwrap();
+        assert_eq!(
+            bytes,
+            npy(
+                "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+                &f8(&[1., 2., 3., 4., 5., 6.])
+            )
+        );
+
+        let options = [
+            NpyOptions {
+                order: Order::Fortran,
+                ..Default::default()
+            },
+            NpyOptions {
+                dtype: Dtype::F4,
+                version: NpyVersion::V2,
+                ..Default::default()
+            },
+        ];
+        for options in options {
+            let mut bytes = vec![];
+            m.write_npy(&mut bytes, &options).unwrap();
+            let data_len = match options.dtype {
+                Dtype::F8 => 6 * 8,
+                Dtype::F4 => 6 * 4,
+            };
+            assert_eq!((bytes.len() - data_len) % 64, 0);
+            assert_eq!(Matrix::read_npy(&bytes[..]).unwrap(), m);
+        }
+
+        let v = vector([1., -2., 0.5]);
+        let mut bytes = vec![];
+        v.write_npy(&mut bytes, &NpyOptions::default()).unwrap();
+        assert_eq!(
+            bytes,
+            npy(
+                "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }",
+                &f8(&[1., -2., 0.5])
+            )
+        );
+        assert_eq!(Vector::read_npy(&bytes[..]).unwrap(), v);
+    }
+}
@@ -1,9 +1,18 @@
+pub mod approx;
 mod equation;
+pub mod io;
 pub mod line;
 mod linear_system;
 pub mod math;
 mod matrix;
+mod parallel;
+pub mod parametric;
+pub mod parse;
+pub mod plane;
+pub mod render;
 pub mod round;
+#[cfg(feature = "serde")]
+mod serialize;
 mod vector;
 
 pub use equation::*;
@@ -1,8 +1,13 @@
-use crate::{equation::Equation, vector, Vector};
+use crate::{
+    equation::{Equation, ZERO_NORMAL_VECTOR_HAS_NO_BASE_POINT},
+    parametric::{parametric_line, ParametricLine},
+    vector, Vector,
+};
 
 pub type Line = Equation<2>;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Intersection {
     Some(Vector<2>),
     None,
@@ -44,6 +49,14 @@ impl Line {
             (-c * k1 + a * k2) / division,
         ]))
     }
+
+    pub fn to_parametric(&self) -> Result<ParametricLine<2>, String> {
+        let base_point = self
+            .base_point()
+            .ok_or(String::from(ZERO_NORMAL_VECTOR_HAS_NO_BASE_POINT))?;
+        let direction = vector([self.normal_vector[1], -self.normal_vector[0]]);
+        Ok(parametric_line(base_point, direction))
+    }
 }
 
 #[cfg(test)]
@@ -69,4 +82,15 @@ mod tests {
         let line_2 = equation(vector([1.773, 8.343]), 9.525);
         assert_eq!(line_1.intersect(&line_2), None);
     }
+
+    #[test]
+    fn to_parametric() {
+        let line = equation(vector([1., -1.]), -3.);
+        let parametric = line.to_parametric().unwrap();
+        assert_eq!(parametric.base_point, vector([-3., 0.]));
+        assert_eq!(parametric.direction, vector([-1., -1.]));
+        assert_eq!(parametric.to_implicit(), Ok(line));
+
+        assert!(equation(vector([0., 0.]), 1.).to_parametric().is_err());
+    }
 }
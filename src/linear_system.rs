@@ -1,23 +1,98 @@
 mod fmt;
 mod ops;
+mod parse;
+pub(crate) mod reduction;
 
 use crate::{
-    equation::Equation,
-    math::{first_nonzero_index, is_zero},
-    vector, Vector,
+    equation::{equation, Equation},
+    math::{first_nonzero_index_relative_to, max_abs, Tolerance},
+    matrix, Matrix, Vector,
 };
 
+use reduction::Reduction;
+
 const NO_SOLUTIONS_MSG: &str = "No solutions";
 const INF_SOLUTIONS_MSG: &str = "Infinitely many solutions";
+// Beyond this condition number about half of the significant digits are lost.
+const ILL_CONDITIONED_THRESHOLD: f64 = 1e8;
+
+/// Rank and consistency data explaining how a system was solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Classification {
+    /// Rank of the coefficient matrix.
+    pub rank: usize,
+    /// Rank of the augmented matrix, greater than `rank` for inconsistent systems.
+    pub augmented_rank: usize,
+    pub pivot_indices: Vec<usize>,
+    pub free_indices: Vec<usize>,
+    /// Index of the original equation which reduces to `0 = c`, if any.
+    pub contradictory_row: Option<usize>,
+}
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Solution<const DIM: usize> {
     Some(Vector<DIM>),
-    None(String),
-    Infinity(String),
+    None(Classification),
+    Infinity(Classification),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolutionKind {
+    Some,
+    None,
+    Infinity,
+}
+
+/// Solutions for several right-hand sides, one column per right-hand side.
+///
+/// Columns without solution are filled with `NaN`, columns with infinitely
+/// many solutions hold the particular solution whose free variables are zero.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solutions<const DIM: usize, const K: usize> {
+    pub values: Matrix<DIM, K>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::array"))]
+    pub kinds: [SolutionKind; K],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::array"))]
+    pub classifications: [Classification; K],
+}
+
+impl<const DIM: usize, const K: usize> Solutions<DIM, K> {
+    pub fn column(&self, col: usize) -> Solution<DIM> {
+        let classification = self.classifications[col].clone();
+        match self.kinds[col] {
+            SolutionKind::Some => Solution::Some(self.values.get_col(col)),
+            SolutionKind::None => Solution::None(classification),
+            SolutionKind::Infinity => Solution::Infinity(classification),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConditionedSolution<const DIM: usize> {
+    pub solution: Solution<DIM>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::number"))]
+    pub condition_number: f64,
 }
 
-#[derive(Clone)]
+impl<const DIM: usize> ConditionedSolution<DIM> {
+    pub fn is_ill_conditioned(&self) -> bool {
+        self.condition_number >= ILL_CONDITIONED_THRESHOLD
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Residual<const LEN: usize> {
+    pub residuals: Vector<LEN>,
+    pub norm: f64,
+}
+
+#[derive(Debug, Clone)]
 pub struct LinearSystem<const DIM: usize, const LEN: usize>([Equation<DIM>; LEN]);
 
 impl<const DIM: usize, const LEN: usize> From<[Equation<DIM>; LEN]> for LinearSystem<DIM, LEN> {
@@ -33,6 +108,20 @@ pub fn linear_system<const DIM: usize, const LEN: usize>(
 }
 
 impl<const DIM: usize, const LEN: usize> LinearSystem<DIM, LEN> {
+    pub fn from_augmented(coefficient_matrix: Matrix<LEN, DIM>, constants: Vector<LEN>) -> Self {
+        LinearSystem(std::array::from_fn(|row| {
+            equation(coefficient_matrix[row], constants[row])
+        }))
+    }
+
+    pub fn coefficient_matrix(&self) -> Matrix<LEN, DIM> {
+        self.0.into_iter().map(|e| e.normal_vector).collect()
+    }
+
+    pub fn constants(&self) -> Vector<LEN> {
+        self.0.into_iter().map(|e| e.constant_term).collect()
+    }
+
     fn coefficient(&self, row: usize, col: usize) -> f64 {
         self[row].normal_vector[col]
     }
@@ -69,140 +158,136 @@ impl<const DIM: usize, const LEN: usize> LinearSystem<DIM, LEN> {
         DIM
     }
 
-    fn indices_of_first_nonzero_terms_in_each_row(&self) -> [Option<usize>; LEN] {
-        self.0.map(|e| first_nonzero_index(e.normal_vector).ok())
+    fn indices_of_first_nonzero_terms_in_each_row(
+        &self,
+        scale: f64,
+        tolerance: &Tolerance,
+    ) -> [Option<usize>; LEN] {
+        self.0
+            .map(|e| first_nonzero_index_relative_to(e.normal_vector, scale, tolerance).ok())
     }
 
-    fn swap_with_row_below_for_nonzero_coefficient_if_able(
-        &mut self,
-        row: usize,
-        col: usize,
-    ) -> bool {
-        let num_equations = LEN;
-
-        for current_row in row + 1..num_equations {
-            let coefficient = self.coefficient(current_row, col);
-            if !is_zero(coefficient) {
-                self.swap_rows(row, current_row);
-                return true;
-            }
-        }
-        false
+    pub(crate) fn compute_triangular_form(&self) -> Self {
+        Reduction::triangular_form(self, &Tolerance::default()).system
     }
 
-    fn clear_coefficient(&mut self, row: usize, col: usize, target_row: usize) {
-        let beta = self.coefficient(row, col);
-        let gamma = self.coefficient(target_row, col);
-        let alpha = -gamma / beta;
-        self.add_multiple_times_row_to_row(alpha, row, target_row);
+    // Reduced Row-Echelon Form
+    pub(crate) fn compute_rref(&self) -> Self {
+        Reduction::rref(self, &Tolerance::default()).system
     }
 
-    fn clear_coefficients_above(&mut self, row: usize, col: usize) {
-        (0..row).for_each(|current_row| {
-            self.clear_coefficient(row, col, current_row);
-        });
+    pub fn compute_solution(&self) -> Solution<DIM> {
+        self.compute_solution_with(&Tolerance::default())
     }
 
-    fn clear_coefficients_below(&mut self, row: usize, col: usize) {
-        let num_equations = LEN;
+    /// Solve the system, deciding pivots and contradictions with `tolerance`.
+    pub fn compute_solution_with(&self, tolerance: &Tolerance) -> Solution<DIM> {
+        Reduction::rref(self, tolerance).solution()
+    }
 
-        (row + 1..num_equations).for_each(|current_row| {
-            self.clear_coefficient(row, col, current_row);
-        });
+    /// Solve the system and report the estimated 2-norm condition number of
+    /// its coefficients, taken as the square root of Hager's estimate for the
+    /// normal equations so that it also covers non-square systems.
+    pub fn compute_conditioned_solution(&self) -> ConditionedSolution<DIM> {
+        let condition_number = self
+            .coefficient_matrix()
+            .gram()
+            .estimate_condition_number()
+            .sqrt();
+        ConditionedSolution {
+            solution: self.compute_solution(),
+            condition_number,
+        }
     }
 
-    fn compute_triangular_form(&self) -> Self {
-        let mut system = self.clone();
-        let num_equations = LEN;
-        let num_variables = DIM;
-
-        let mut col = 0;
-        (0..num_equations).for_each(|row| {
-            while col < num_variables {
-                let coefficient = system.coefficient(row, col);
-                if is_zero(coefficient) {
-                    let swap_succeeded =
-                        system.swap_with_row_below_for_nonzero_coefficient_if_able(row, col);
-                    if !swap_succeeded {
-                        col += 1;
-                        continue;
-                    }
-                }
-                system.clear_coefficients_below(row, col);
-                col += 1;
+    /// Solve the system, then repeatedly correct the solution with the same
+    /// reduction until the residual stops shrinking or `max_iterations` is reached.
+    pub fn compute_refined_solution(&self, max_iterations: usize) -> Solution<DIM> {
+        let reduction = Reduction::rref(self, &Tolerance::default());
+        let mut solution = match reduction.solution() {
+            Solution::Some(solution) => solution,
+            s => return s,
+        };
+        let mut residual = self.residual(&solution);
+
+        for _ in 0..max_iterations {
+            let reduced_residuals = reduction.replay_vector(&residual.residuals);
+            let correction = (0..DIM).map(|i| reduced_residuals[i]).collect();
+            let refined_solution = solution + correction;
+            let refined_residual = self.residual(&refined_solution);
+            if refined_residual.norm >= residual.norm {
                 break;
             }
-        });
+            solution = refined_solution;
+            residual = refined_residual;
+        }
 
-        system
+        Solution::Some(solution)
     }
 
-    fn scale_row_to_make_coefficient_equal_one(&mut self, row: usize, col: usize) {
-        let coefficient = self.coefficient(row, col);
-        self.multiply_coefficient_and_row(1. / coefficient, row)
+    /// Residuals `constant_term - normal_vector · solution` of every equation.
+    pub fn residual(&self, solution: &Vector<DIM>) -> Residual<LEN> {
+        let residuals: Vector<LEN> = self
+            .0
+            .into_iter()
+            .map(|e| e.constant_term - e.normal_vector.dot(solution))
+            .collect();
+        Residual {
+            residuals,
+            norm: residuals.magnitude(),
+        }
     }
 
-    // Reduced Row-Echelon Form
-    fn compute_rref(&self) -> Self {
-        let mut tf = self.compute_triangular_form();
-        let num_equations = LEN;
-        let pivot_indices = tf.indices_of_first_nonzero_terms_in_each_row();
-
-        (0..num_equations).rev().for_each(|row| {
-            let col = pivot_indices[row];
-            if let Some(col) = col {
-                tf.scale_row_to_make_coefficient_equal_one(row, col);
-                tf.clear_coefficients_above(row, col);
-            }
-        });
-
-        tf
+    pub fn verify(&self, solution: &Vector<DIM>, tolerance: f64) -> bool {
+        self.residual(solution).norm <= tolerance
     }
 
-    pub fn compute_solution(&self) -> Solution<DIM> {
-        let rref = self.compute_rref();
-
-        if let Some(s) = rref.raise_exception_if_contradictory_equation() {
-            return s;
-        }
+    /// Solve the coefficients of the system against every column of `constants`,
+    /// reducing the coefficients only once. The constant terms of the system are ignored.
+    pub fn compute_solutions<const K: usize>(
+        &self,
+        constants: &Matrix<LEN, K>,
+    ) -> Solutions<DIM, K> {
+        let reduction = Reduction::rref(self, &Tolerance::default());
+        let reduced_constants = reduction.replay(constants);
+        let pivot_indices = reduction.pivot_indices();
+        let num_pivots = reduction.rank();
+
+        let contradictory_rows: [Option<usize>; K] = std::array::from_fn(|col| {
+            reduction.contradictory_row(
+                |row| reduced_constants[row][col],
+                max_abs(constants.get_col(col)),
+            )
+        });
+        let kinds = contradictory_rows.map(|contradictory_row| {
+            if contradictory_row.is_some() {
+                SolutionKind::None
+            } else if num_pivots < DIM {
+                SolutionKind::Infinity
+            } else {
+                SolutionKind::Some
+            }
+        });
+        let classifications =
+            contradictory_rows.map(|contradictory_row| reduction.classification(contradictory_row));
 
-        if let Some(s) = rref.raise_exception_if_too_few_pivots() {
-            return s;
+        let mut values = [[0.; K]; DIM];
+        for (row, pivot_index) in pivot_indices.into_iter().enumerate() {
+            if let Some(variable) = pivot_index {
+                values[variable] = std::array::from_fn(|col| reduced_constants[row][col]);
+            }
         }
-
-        let mut arr = [0.; DIM];
-        (0..DIM).for_each(|i| arr[i] = rref.0[i].constant_term);
-        Solution::Some(vector(arr))
-    }
-
-    fn raise_exception_if_contradictory_equation(&self) -> Option<Solution<DIM>> {
-        for equation in self.0 {
-            if first_nonzero_index(equation.normal_vector).is_err() {
-                let constant_term = equation.constant_term;
-                if !is_zero(constant_term) {
-                    return Some(Solution::None(String::from(NO_SOLUTIONS_MSG)));
-                }
+        for (col, kind) in kinds.iter().enumerate() {
+            if *kind == SolutionKind::None {
+                values.iter_mut().for_each(|row| row[col] = f64::NAN);
             }
         }
 
-        None
-    }
-
-    fn raise_exception_if_too_few_pivots(&self) -> Option<Solution<DIM>> {
-        let pivot_indices = self.indices_of_first_nonzero_terms_in_each_row();
-        let num_pivots = {
-            pivot_indices.into_iter().fold(0, |sum, index| match index {
-                Some(_) => sum + 1,
-                None => sum,
-            })
-        } as usize;
-        let num_variables = DIM;
-
-        if num_pivots < num_variables {
-            return Some(Solution::Infinity(String::from(INF_SOLUTIONS_MSG)));
+        Solutions {
+            values: matrix(values),
+            kinds,
+            classifications,
         }
-
-        None
     }
 }
 
@@ -210,10 +295,53 @@ impl<const DIM: usize, const LEN: usize> LinearSystem<DIM, LEN> {
 mod tests {
     use crate::{
         equation,
-        linear_system::{linear_system, Solution, INF_SOLUTIONS_MSG, NO_SOLUTIONS_MSG},
-        vector,
+        linear_system::{linear_system, Classification, LinearSystem, Solution, SolutionKind},
+        math::Tolerance,
+        matrix, vector,
     };
 
+    fn classification(
+        rank: usize,
+        augmented_rank: usize,
+        pivot_indices: &[usize],
+        free_indices: &[usize],
+        contradictory_row: Option<usize>,
+    ) -> Classification {
+        Classification {
+            rank,
+            augmented_rank,
+            pivot_indices: pivot_indices.to_vec(),
+            free_indices: free_indices.to_vec(),
+            contradictory_row,
+        }
+    }
+
+    #[test]
+    fn from_augmented() {
+        let m = matrix([[1., 1., 1.], [0., 1., 0.]]);
+        let s = LinearSystem::from_augmented(m, vector([1., 2.]));
+        assert!(
+            s[0] == equation(vector([1., 1., 1.]), 1.)
+                && s[1] == equation(vector([0., 1., 0.]), 2.)
+        );
+    }
+
+    #[test]
+    fn coefficient_matrix() {
+        let e0 = equation(vector([1., 1., 1.]), 1.);
+        let e1 = equation(vector([0., 1., 0.]), 2.);
+        let s = linear_system([e0, e1]);
+        assert_eq!(s.coefficient_matrix(), matrix([[1., 1., 1.], [0., 1., 0.]]));
+    }
+
+    #[test]
+    fn constants() {
+        let e0 = equation(vector([1., 1., 1.]), 1.);
+        let e1 = equation(vector([0., 1., 0.]), 2.);
+        let s = linear_system([e0, e1]);
+        assert_eq!(s.constants(), vector([1., 2.]));
+    }
+
     #[test]
     fn indices_of_first_nonzero_terms_in_each_row() {
         let e0 = equation(vector([1., 1., 1.]), 1.);
@@ -223,7 +351,7 @@ mod tests {
 
         let s = linear_system([e0, e1, e2, e3]);
         assert_eq!(
-            s.indices_of_first_nonzero_terms_in_each_row(),
+            s.indices_of_first_nonzero_terms_in_each_row(0., &Tolerance::default()),
             [Some(0), Some(1), Some(0), Some(0)]
         );
     }
@@ -371,7 +499,10 @@ mod tests {
         let e2 = equation(vector([-2.931, -0.589, 5.183]), -4.075);
         let s = linear_system([e1, e2]);
         let solution = s.compute_solution();
-        assert_eq!(solution, Solution::None(String::from(NO_SOLUTIONS_MSG)));
+        assert_eq!(
+            solution,
+            Solution::None(classification(1, 2, &[0], &[1, 2], Some(1)))
+        );
 
         let e1 = equation(vector([8.631, 5.112, -1.816]), -5.113);
         let e2 = equation(vector([4.315, 11.132, -5.27]), -6.775);
@@ -380,7 +511,7 @@ mod tests {
         let solution = s.compute_solution();
         assert_eq!(
             solution,
-            Solution::Infinity(String::from(INF_SOLUTIONS_MSG))
+            Solution::Infinity(classification(2, 2, &[0, 1], &[2], None))
         );
 
         let e1 = equation(vector([5.262, 2.739, -9.878]), -3.441);
@@ -399,6 +530,145 @@ mod tests {
         let e2 = equation(vector([-0.131, -0.131]), 0.319);
         let s = linear_system([e1, e2]);
         let solution = s.compute_solution();
-        assert_eq!(solution, Solution::None(String::from(NO_SOLUTIONS_MSG)));
+        assert_eq!(
+            solution,
+            Solution::None(classification(1, 2, &[0], &[1], Some(1)))
+        );
+    }
+
+    #[test]
+    fn compute_solutions() {
+        let e1 = equation(vector([0., 1., 1.]), 0.);
+        let e2 = equation(vector([1., -1., 1.]), 0.);
+        let e3 = equation(vector([1., 2., -5.]), 0.);
+        let s = linear_system([e1, e2, e3]);
+        let solutions = s.compute_solutions(&matrix([[1., 0.], [2., 0.], [3., 9.]]));
+        assert_eq!(solutions.kinds, [SolutionKind::Some, SolutionKind::Some]);
+        assert_eq!(
+            solutions.values.round(3),
+            matrix([[23. / 9., 2.], [7. / 9., 1.], [2. / 9., -1.]]).round(3)
+        );
+        if let Solution::Some(vec) = solutions.column(1) {
+            assert_eq!(vec.round(3), vector([2., 1., -1.]));
+        }
+
+        let e1 = equation(vector([1., 1., 1.]), 0.);
+        let e2 = equation(vector([1., 1., 1.]), 0.);
+        let s = linear_system([e1, e2]);
+        let solutions = s.compute_solutions(&matrix([[1., 1.], [1., 2.]]));
+        assert_eq!(
+            solutions.kinds,
+            [SolutionKind::Infinity, SolutionKind::None]
+        );
+        assert_eq!(solutions.values.get_col(0), vector([1., 0., 0.]));
+        assert!(solutions.values.get_col(1)[0].is_nan());
+        assert_eq!(
+            solutions.column(0),
+            Solution::Infinity(classification(1, 1, &[0], &[1, 2], None))
+        );
+        assert_eq!(
+            solutions.column(1),
+            Solution::None(classification(1, 2, &[0], &[1, 2], Some(1)))
+        );
+    }
+
+    #[test]
+    fn residual() {
+        let e1 = equation(vector([1., 1.]), 3.);
+        let e2 = equation(vector([1., -1.]), 1.);
+        let e3 = equation(vector([2., 0.]), 0.);
+        let s = linear_system([e1, e2, e3]);
+        let residual = s.residual(&vector([2., 1.]));
+        assert_eq!(residual.residuals, vector([0., 0., -4.]));
+        assert_eq!(residual.norm, 4.);
+    }
+
+    #[test]
+    fn verify() {
+        let e1 = equation(vector([1., 1.]), 3.);
+        let e2 = equation(vector([1., -1.]), 1.);
+        let s = linear_system([e1, e2]);
+        assert!(s.verify(&vector([2., 1.]), 1e-10));
+        assert!(!s.verify(&vector([2., 1.1]), 1e-10));
+        assert!(s.verify(&vector([2., 1.1]), 0.2));
+    }
+
+    #[test]
+    fn compute_refined_solution() {
+        let e1 = equation(vector([5.262, 2.739, -9.878]), -3.441);
+        let e2 = equation(vector([5.111, 6.358, 7.638]), -2.152);
+        let e3 = equation(vector([2.016, -9.924, -1.367]), -9.278);
+        let e4 = equation(vector([2.167, -13.543, -18.883]), -10.567);
+        let s = linear_system([e1, e2, e3, e4]);
+        let (Solution::Some(solution), Solution::Some(refined)) =
+            (s.compute_solution(), s.compute_refined_solution(10))
+        else {
+            panic!("expected a unique solution");
+        };
+        assert_eq!(refined.round(3), vector([-1.177, 0.707, -0.083]));
+        assert!(s.residual(&refined).norm <= s.residual(&solution).norm);
+
+        let e1 = equation(vector([0.786, 0.786]), -0.714);
+        let e2 = equation(vector([-0.131, -0.131]), 0.319);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            s.compute_refined_solution(10),
+            Solution::None(classification(1, 2, &[0], &[1], Some(1)))
+        );
+    }
+
+    #[test]
+    fn compute_conditioned_solution() {
+        let e1 = equation(vector([1., 2.]), 3.);
+        let e2 = equation(vector([3., 4.]), 7.);
+        let s = linear_system([e1, e2]);
+        let conditioned = s.compute_conditioned_solution();
+        assert!(conditioned.condition_number > 10. && conditioned.condition_number < 30.);
+        assert!(!conditioned.is_ill_conditioned());
+        if let Solution::Some(vec) = conditioned.solution {
+            assert_eq!(vec.round(3), vector([1., 1.]));
+        } else {
+            panic!("expected a unique solution");
+        }
+
+        let e1 = equation(vector([1., 1.]), 2.);
+        let e2 = equation(vector([1., 1. + 1e-9]), 2.);
+        let s = linear_system([e1, e2]);
+        assert!(s.compute_conditioned_solution().is_ill_conditioned());
+
+        let e1 = equation(vector([4., 2.]), 1.);
+        let e2 = equation(vector([14., 7.]), 1.);
+        let s = linear_system([e1, e2]);
+        let conditioned = s.compute_conditioned_solution();
+        assert_eq!(conditioned.condition_number, f64::INFINITY);
+        assert!(conditioned.is_ill_conditioned());
+    }
+
+    #[test]
+    fn compute_solution_with() {
+        let e1 = equation(vector([1e-12, 2e-12]), 3e-12);
+        let e2 = equation(vector([1e-12, -1e-12]), 0.);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            s.compute_solution(),
+            Solution::Infinity(classification(0, 0, &[], &[0, 1], None))
+        );
+        if let Solution::Some(vec) = s.compute_solution_with(&Tolerance::Relative(1e-10)) {
+            assert_eq!(vec.round(3), vector([1., 1.]));
+        } else {
+            panic!("expected a unique solution");
+        }
+
+        let e1 = equation(vector([1e12, 1e12]), 1e12);
+        let e2 = equation(vector([1e12, 1e12]), 1e12 + 1e-3);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            s.compute_solution(),
+            Solution::None(classification(1, 2, &[0], &[1], Some(1)))
+        );
+        assert_eq!(
+            s.compute_solution_with(&Tolerance::Relative(1e-10)),
+            Solution::Infinity(classification(1, 1, &[0], &[1], None))
+        );
     }
 }
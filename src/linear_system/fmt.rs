@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::LinearSystem;
+use super::{LinearSystem, Solution, INF_SOLUTIONS_MSG, NO_SOLUTIONS_MSG};
 
 impl<const DIM: usize, const LEN: usize> Display for LinearSystem<DIM, LEN> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -16,6 +16,48 @@ impl<const DIM: usize, const LEN: usize> Display for LinearSystem<DIM, LEN> {
     }
 }
 
+impl<const DIM: usize> Display for Solution<DIM> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solution::Some(vector) => {
+                for index in 0..DIM {
+                    if index != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "x_{} = ", index)?;
+                    vector[index].fmt(f)?;
+                }
+                Ok(())
+            }
+            Solution::None(classification) => {
+                write!(
+                    f,
+                    "{}: rank {} < augmented rank {}",
+                    NO_SOLUTIONS_MSG, classification.rank, classification.augmented_rank
+                )?;
+                if let Some(row) = classification.contradictory_row {
+                    write!(f, ", Equation {} reduces to 0 = c", row + 1)?;
+                }
+                Ok(())
+            }
+            Solution::Infinity(classification) => {
+                write!(
+                    f,
+                    "{}: rank {} < {} variables, free variables ",
+                    INF_SOLUTIONS_MSG, classification.rank, DIM
+                )?;
+                for (i, index) in classification.free_indices.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "x_{}", index)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{equation, linear_system, vector};
@@ -38,4 +80,31 @@ Equation 4: x_0 - 2x_2 = 2
 "#
         );
     }
+
+    #[test]
+    fn fmt_solution() {
+        let e1 = equation(vector([1., 1.]), 3.);
+        let e2 = equation(vector([1., -1.]), 1.);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            format!("{:.1}", s.compute_solution()),
+            "x_0 = 2.0, x_1 = 1.0"
+        );
+
+        let e1 = equation(vector([1., 1., 1.]), 1.);
+        let e2 = equation(vector([2., 2., 2.]), 3.);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            format!("{}", s.compute_solution()),
+            "No solutions: rank 1 < augmented rank 2, Equation 2 reduces to 0 = c"
+        );
+
+        let e1 = equation(vector([1., 1., 1.]), 1.);
+        let e2 = equation(vector([0., 0., 1.]), 3.);
+        let s = linear_system([e1, e2]);
+        assert_eq!(
+            format!("{}", s.compute_solution()),
+            "Infinitely many solutions: rank 2 < 3 variables, free variables x_1"
+        );
+    }
 }
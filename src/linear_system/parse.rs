@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use crate::{
+    equation::{parse::parse_equation, Equation},
+    parse::ParseError,
+};
+
+use super::LinearSystem;
+
+const HEADER: &str = "Linear System:";
+const EMPTY: &str = "No Equation";
+
+/// Column after an `Equation N:` prefix, if `line` has one.
+fn skip_label(line: &str) -> usize {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let Some(rest) = trimmed.strip_prefix("Equation") else {
+        return 0;
+    };
+    let number = rest.trim_start();
+    let digits = number.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 || !number[digits..].starts_with(':') {
+        return 0;
+    }
+    indent + "Equation".len() + (rest.len() - number.len()) + digits + 1
+}
+
+/// One equation per line, as printed by `Display` or without the header and labels.
+impl<const DIM: usize, const LEN: usize> FromStr for LinearSystem<DIM, LEN> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut equations: Vec<Equation<DIM>> = vec![];
+        let mut last_line = 1;
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed == HEADER || trimmed == EMPTY {
+                continue;
+            }
+            last_line = line_number;
+            if equations.len() == LEN {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    format!("expected {} equations", LEN),
+                ));
+            }
+            let skipped = skip_label(line);
+            let column = line[..skipped].chars().count() + 1;
+            equations.push(parse_equation(&line[skipped..], line_number, column)?);
+        }
+
+        let len = equations.len();
+        let equations: [Equation<DIM>; LEN] = equations.try_into().map_err(|_| {
+            ParseError::new(
+                last_line,
+                1,
+                format!("expected {} equations, found {}", LEN, len),
+            )
+        })?;
+        Ok(LinearSystem(equations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        approx::ApproxEq, equation, linear_system, math::Tolerance, parse::ParseError, vector,
+        LinearSystem,
+    };
+
+    #[test]
+    fn from_str() {
+        let e0 = equation(vector([1., 1., 1.]), 1.);
+        let e1 = equation(vector([0., 1., 0.]), 2.);
+        let e2 = equation(vector([1., 1., -1.]), 3.);
+        let e3 = equation(vector([1., 0., -2.]), 2.);
+        let s = linear_system([e0, e1, e2, e3]);
+        let parsed: LinearSystem<3, 4> = format!("{}", s).parse().unwrap();
+        assert!(parsed.approx_eq(&s, &Tolerance::default()));
+
+        let parsed: LinearSystem<3, 2> = "x + y + z = 1\n\n  2y = 4\n".parse().unwrap();
+        let expected = linear_system([e0, equation(vector([0., 2., 0.]), 4.)]);
+        assert!(parsed.approx_eq(&expected, &Tolerance::default()));
+        assert!(!parsed.approx_eq(&linear_system([e0, e1]), &Tolerance::default()));
+
+        let parsed: LinearSystem<2, 0> = "No Equation".parse().unwrap();
+        assert!(parsed.is_empty());
+    }
+
+    #[test]
+    fn from_str_error() {
+        let parse = |s: &str| s.parse::<LinearSystem<2, 2>>().map(|s| s.0);
+        assert_eq!(
+            parse("Linear System:\nEquation 1: x = 1\nEquation 2: x + = 2"),
+            Err(ParseError::new(3, 17, "expected a term"))
+        );
+        assert_eq!(
+            parse("x = 1"),
+            Err(ParseError::new(1, 1, "expected 2 equations, found 1"))
+        );
+        assert_eq!(
+            parse("x = 1\ny = 2\nx + y = 3"),
+            Err(ParseError::new(3, 1, "expected 2 equations"))
+        );
+    }
+}
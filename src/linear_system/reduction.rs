@@ -0,0 +1,392 @@
+use std::ops::Range;
+
+use crate::{
+    math::{max_abs, Tolerance},
+    parallel, vector, Matrix, Vector,
+};
+
+use super::{Classification, LinearSystem, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RowOperation {
+    Swap(usize, usize),
+    Multiply(f64, usize),
+    AddMultiple(f64, usize, usize),
+}
+
+impl RowOperation {
+    fn apply<const DIM: usize, const LEN: usize>(&self, system: &mut LinearSystem<DIM, LEN>) {
+        use RowOperation::*;
+        match *self {
+            Swap(row1, row2) => system.swap_rows(row1, row2),
+            Multiply(coefficient, row) => system.multiply_coefficient_and_row(coefficient, row),
+            AddMultiple(coefficient, row_to_add, row_to_be_added_to) => {
+                system.add_multiple_times_row_to_row(coefficient, row_to_add, row_to_be_added_to)
+            }
+        }
+    }
+
+    /// The operation on one column of constants, entry `i` belongs to row `i`.
+    fn apply_to_column<const LEN: usize>(&self, column: &mut Vector<LEN>) {
+        use RowOperation::*;
+        match *self {
+            Swap(row1, row2) => column.as_mut_slice().swap(row1, row2),
+            Multiply(coefficient, row) => column[row] *= coefficient,
+            AddMultiple(coefficient, row_to_add, row_to_be_added_to) => {
+                column[row_to_be_added_to] += column[row_to_add] * coefficient
+            }
+        }
+    }
+}
+
+/// A reduced linear system together with the row operations that produced it,
+/// so the same elimination can be replayed on other right-hand sides.
+///
+/// Pivot decisions compare coefficients against the largest coefficient of the
+/// original system, and constant terms against the largest constant term.
+#[derive(Clone)]
+pub(crate) struct Reduction<const DIM: usize, const LEN: usize> {
+    pub(crate) system: LinearSystem<DIM, LEN>,
+    operations: Vec<RowOperation>,
+    tolerance: Tolerance,
+    scale: f64,
+    constant_scale: f64,
+    /// Pivots fixed by the triangular form, scaling rows to one must not change them.
+    pivots: Option<[Option<usize>; LEN]>,
+}
+
+impl<const DIM: usize, const LEN: usize> Reduction<DIM, LEN> {
+    fn new(system: &LinearSystem<DIM, LEN>, tolerance: &Tolerance) -> Self {
+        Reduction {
+            system: system.clone(),
+            operations: vec![],
+            tolerance: *tolerance,
+            scale: max_abs(system.0.iter().flat_map(|e| e.normal_vector)),
+            constant_scale: max_abs(system.constants()),
+            pivots: None,
+        }
+    }
+
+    fn is_negligible(&self, coefficient: f64) -> bool {
+        self.tolerance.is_negligible(coefficient, self.scale)
+    }
+
+    pub(crate) fn pivot_indices(&self) -> [Option<usize>; LEN] {
+        self.pivots.unwrap_or_else(|| {
+            self.system
+                .indices_of_first_nonzero_terms_in_each_row(self.scale, &self.tolerance)
+        })
+    }
+
+    pub(crate) fn rank(&self) -> usize {
+        self.pivot_indices()
+            .into_iter()
+            .filter(Option::is_some)
+            .count()
+    }
+
+    /// First reduced row without pivot whose constant term isn't negligible, i.e. `0 = c`.
+    pub(crate) fn contradictory_row(
+        &self,
+        constant_term: impl Fn(usize) -> f64,
+        constant_scale: f64,
+    ) -> Option<usize> {
+        let pivot_indices = self.pivot_indices();
+        (0..LEN).find(|&row| {
+            pivot_indices[row].is_none()
+                && !self
+                    .tolerance
+                    .is_negligible(constant_term(row), constant_scale)
+        })
+    }
+
+    /// Index in the original system of every reduced row, following the swaps.
+    fn row_origins(&self) -> [usize; LEN] {
+        let mut origins = std::array::from_fn(|row| row);
+        self.operations.iter().for_each(|operation| {
+            if let RowOperation::Swap(row1, row2) = *operation {
+                origins.swap(row1, row2);
+            }
+        });
+        origins
+    }
+
+    pub(crate) fn classification(&self, contradictory_row: Option<usize>) -> Classification {
+        let pivot_indices: Vec<usize> = self.pivot_indices().into_iter().flatten().collect();
+        let free_indices = (0..DIM)
+            .filter(|index| !pivot_indices.contains(index))
+            .collect();
+        let rank = pivot_indices.len();
+        Classification {
+            rank,
+            augmented_rank: rank + usize::from(contradictory_row.is_some()),
+            pivot_indices,
+            free_indices,
+            contradictory_row: contradictory_row.map(|row| self.row_origins()[row]),
+        }
+    }
+
+    fn apply(&mut self, operation: RowOperation) {
+        operation.apply(&mut self.system);
+        self.operations.push(operation);
+    }
+
+    fn swap_with_row_below_for_nonzero_coefficient_if_able(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> bool {
+        let num_equations = LEN;
+
+        for current_row in row + 1..num_equations {
+            let coefficient = self.system.coefficient(current_row, col);
+            if !self.is_negligible(coefficient) {
+                self.apply(RowOperation::Swap(row, current_row));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Add a multiple of `row` to every row of `targets` to clear their `col`
+    /// coefficient. The pivot row isn't a target, so the targets are independent
+    /// and updated in parallel.
+    fn clear_coefficients(&mut self, row: usize, col: usize, targets: Range<usize>) {
+        let pivot = self.system[row];
+        let beta = pivot.normal_vector[col];
+        let alphas: Vec<f64> = targets
+            .clone()
+            .map(|target_row| -self.system.coefficient(target_row, col) / beta)
+            .collect();
+        parallel::for_each_row(
+            &mut self.system.0[targets.clone()],
+            alphas.len() * DIM,
+            |index, equation| *equation = pivot * alphas[index] + *equation,
+        );
+        self.operations.extend(
+            targets
+                .zip(alphas)
+                .map(|(target_row, alpha)| RowOperation::AddMultiple(alpha, row, target_row)),
+        );
+    }
+
+    fn clear_coefficients_above(&mut self, row: usize, col: usize) {
+        self.clear_coefficients(row, col, 0..row);
+    }
+
+    fn clear_coefficients_below(&mut self, row: usize, col: usize) {
+        let num_equations = LEN;
+
+        self.clear_coefficients(row, col, row + 1..num_equations);
+    }
+
+    fn scale_row_to_make_coefficient_equal_one(&mut self, row: usize, col: usize) {
+        let coefficient = self.system.coefficient(row, col);
+        self.apply(RowOperation::Multiply(1. / coefficient, row));
+    }
+
+    pub(crate) fn triangular_form(system: &LinearSystem<DIM, LEN>, tolerance: &Tolerance) -> Self {
+        let mut reduction = Self::new(system, tolerance);
+        let num_equations = LEN;
+        let num_variables = DIM;
+
+        let mut col = 0;
+        (0..num_equations).for_each(|row| {
+            while col < num_variables {
+                let coefficient = reduction.system.coefficient(row, col);
+                if reduction.is_negligible(coefficient) {
+                    let swap_succeeded =
+                        reduction.swap_with_row_below_for_nonzero_coefficient_if_able(row, col);
+                    if !swap_succeeded {
+                        col += 1;
+                        continue;
+                    }
+                }
+                reduction.clear_coefficients_below(row, col);
+                col += 1;
+                break;
+            }
+        });
+
+        reduction
+    }
+
+    // Reduced Row-Echelon Form
+    pub(crate) fn rref(system: &LinearSystem<DIM, LEN>, tolerance: &Tolerance) -> Self {
+        let mut reduction = Self::triangular_form(system, tolerance);
+        let num_equations = LEN;
+        let pivot_indices = reduction.pivot_indices();
+        reduction.pivots = Some(pivot_indices);
+
+        (0..num_equations).rev().for_each(|row| {
+            let col = pivot_indices[row];
+            if let Some(col) = col {
+                reduction.scale_row_to_make_coefficient_equal_one(row, col);
+                reduction.clear_coefficients_above(row, col);
+            }
+        });
+
+        reduction
+    }
+
+    // Must be called on a reduction in Reduced Row-Echelon Form
+    pub(crate) fn solution(&self) -> Solution<DIM> {
+        if let Some(s) = self.raise_exception_if_contradictory_equation() {
+            return s;
+        }
+
+        if let Some(s) = self.raise_exception_if_too_few_pivots() {
+            return s;
+        }
+
+        let mut arr = [0.; DIM];
+        (0..DIM).for_each(|i| arr[i] = self.system[i].constant_term);
+        Solution::Some(vector(arr))
+    }
+
+    fn raise_exception_if_contradictory_equation(&self) -> Option<Solution<DIM>> {
+        let contradictory_row =
+            self.contradictory_row(|row| self.system[row].constant_term, self.constant_scale);
+        contradictory_row.map(|_| Solution::None(self.classification(contradictory_row)))
+    }
+
+    fn raise_exception_if_too_few_pivots(&self) -> Option<Solution<DIM>> {
+        let num_pivots = self.rank();
+        let num_variables = DIM;
+
+        if num_pivots < num_variables {
+            return Some(Solution::Infinity(self.classification(None)));
+        }
+
+        None
+    }
+
+    /// Apply the recorded row operations to every column of `constants`.
+    pub(crate) fn replay<const K: usize>(&self, constants: &Matrix<LEN, K>) -> Matrix<LEN, K> {
+        let mut columns = constants.transpose();
+        let work = self.operations.len() * K;
+        parallel::for_each_row(columns.as_mut_rows(), work, |_, column| {
+            self.operations
+                .iter()
+                .for_each(|operation| operation.apply_to_column(column))
+        });
+        columns.transpose()
+    }
+
+    pub(crate) fn replay_vector(&self, constants: &Vector<LEN>) -> Vector<LEN> {
+        self.replay(&constants.to_col_matrix()).get_col(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        equation, linear_system, math::Tolerance, matrix, matrix::fill, parallel::THRESHOLD,
+        vector, LinearSystem, Matrix,
+    };
+
+    use super::{Reduction, RowOperation};
+
+    #[test]
+    fn apply_to_column() {
+        let mut column = vector([1., 3.]);
+        RowOperation::Swap(0, 1).apply_to_column(&mut column);
+        assert_eq!(column, vector([3., 1.]));
+
+        RowOperation::Multiply(2., 1).apply_to_column(&mut column);
+        assert_eq!(column, vector([3., 2.]));
+
+        RowOperation::AddMultiple(-1., 1, 0).apply_to_column(&mut column);
+        assert_eq!(column, vector([1., 2.]));
+    }
+
+    #[test]
+    fn replay() {
+        let e1 = equation(vector([0., 1., 1.]), 1.);
+        let e2 = equation(vector([1., -1., 1.]), 2.);
+        let e3 = equation(vector([1., 2., -5.]), 3.);
+        let s = linear_system([e1, e2, e3]);
+        let reduction = Reduction::rref(&s, &Tolerance::default());
+
+        let constants = matrix([[1., 0.], [2., 1.], [3., 0.]]);
+        let replayed = reduction.replay(&constants);
+        assert_eq!(
+            replayed.get_col(0).round(3),
+            reduction.system.constants().round(3)
+        );
+        assert_eq!(
+            replayed.get_col(0).round(3),
+            vector([23. / 9., 7. / 9., 2. / 9.]).round(3)
+        );
+    }
+
+    #[test]
+    fn replay_parallel_bit_identical() {
+        let s = LinearSystem::from_augmented(fill::<32, 32>(1), vector([0.; 32]));
+        let reduction = Reduction::rref(&s, &Tolerance::default());
+        // All columns together are past the threshold, a single column isn't.
+        let work = reduction.operations.len();
+        assert!(work * 64 >= THRESHOLD && work < THRESHOLD);
+
+        let constants: Matrix<32, 64> = fill(2);
+        let replayed = reduction.replay(&constants);
+        for col in 0..64 {
+            let serial = reduction.replay_vector(&constants.get_col(col));
+            for row in 0..32 {
+                assert_eq!(replayed[row][col].to_bits(), serial[row].to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn replay_vector() {
+        let e1 = equation(vector([0., 1., 1.]), 1.);
+        let e2 = equation(vector([1., -1., 1.]), 2.);
+        let e3 = equation(vector([1., 2., -5.]), 3.);
+        let s = linear_system([e1, e2, e3]);
+        let reduction = Reduction::rref(&s, &Tolerance::default());
+        assert_eq!(
+            reduction.replay_vector(&vector([1., 2., 3.])).round(3),
+            reduction.system.constants().round(3)
+        );
+    }
+
+    #[test]
+    fn pivot_indices() {
+        let e1 = equation(vector([1e9, 1e9]), 1.);
+        let e2 = equation(vector([1e-3, 2e9]), 1.);
+        let s = linear_system([e1, e2]);
+        let reduction = Reduction::triangular_form(&s, &Tolerance::default());
+        assert_eq!(reduction.pivot_indices(), [Some(0), Some(1)]);
+
+        let reduction = Reduction::triangular_form(&s, &Tolerance::Relative(1e-10));
+        assert_eq!(reduction.pivot_indices(), [Some(0), Some(1)]);
+        assert_eq!(reduction.rank(), 2);
+
+        let e1 = equation(vector([1e-12, 2e-12]), 1.);
+        let e2 = equation(vector([1e-12, 3e-12]), 1.);
+        let s = linear_system([e1, e2]);
+        let reduction = Reduction::triangular_form(&s, &Tolerance::default());
+        assert_eq!(reduction.rank(), 0);
+
+        let reduction = Reduction::triangular_form(&s, &Tolerance::Relative(1e-10));
+        assert_eq!(reduction.rank(), 2);
+    }
+
+    #[test]
+    fn classification() {
+        let e1 = equation(vector([1., 1., 1.]), 1.);
+        let e2 = equation(vector([1., 1., 1.]), 1.);
+        let e3 = equation(vector([0., 0., 1.]), 2.);
+        let e4 = equation(vector([2., 2., 2.]), 3.);
+        let s = linear_system([e1, e2, e3, e4]);
+        let reduction = Reduction::rref(&s, &Tolerance::default());
+        let classification = reduction.classification(Some(3));
+        assert_eq!(classification.rank, 2);
+        assert_eq!(classification.augmented_rank, 3);
+        assert_eq!(classification.pivot_indices, vec![0, 2]);
+        assert_eq!(classification.free_indices, vec![1]);
+        assert_eq!(reduction.row_origins(), [0, 2, 1, 3]);
+        assert_eq!(classification.contradictory_row, Some(3));
+    }
+}
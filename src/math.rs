@@ -3,6 +3,133 @@ use std::f64::consts::PI;
 const NO_NONZERO_ELEMENTS_FOUND: &str = "No nonzero elements found.";
 const TOLERANCE: f64 = 1e-10;
 
+/// Policy deciding when two floats are considered equal, or a value negligible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tolerance {
+    /// `|value - target| < tolerance`
+    Absolute(f64),
+    /// `|value - target| <= tolerance * max(|value|, |target|)`
+    Relative(f64),
+    /// Equal when either the absolute or the relative comparison holds.
+    Combined { absolute: f64, relative: f64 },
+    /// At most the given number of representable floats apart.
+    Ulps(u32),
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::Absolute(TOLERANCE)
+    }
+}
+
+impl Tolerance {
+    pub fn approx_eq(&self, value: f64, target: f64) -> bool {
+        let difference = (value - target).abs();
+        match *self {
+            Tolerance::Absolute(absolute) => difference < absolute,
+            Tolerance::Relative(relative) => difference <= relative * value.abs().max(target.abs()),
+            Tolerance::Combined { absolute, relative } => {
+                Tolerance::Absolute(absolute).approx_eq(value, target)
+                    || Tolerance::Relative(relative).approx_eq(value, target)
+            }
+            Tolerance::Ulps(ulps) => ulps_eq(value, target, ulps),
+        }
+    }
+
+    pub fn approx_ne(&self, value: f64, target: f64) -> bool {
+        !self.approx_eq(value, target)
+    }
+
+    pub fn is_zero(&self, value: f64) -> bool {
+        self.approx_eq(value, 0.)
+    }
+
+    /// Whether `value` is negligible next to the magnitude `scale`,
+    /// e.g. an eliminated coefficient next to the largest one of a system.
+    pub fn is_negligible(&self, value: f64, scale: f64) -> bool {
+        match *self {
+            Tolerance::Absolute(absolute) => value.abs() < absolute,
+            Tolerance::Relative(relative) => value.abs() <= relative * scale.abs(),
+            Tolerance::Combined { absolute, relative } => {
+                Tolerance::Absolute(absolute).is_negligible(value, scale)
+                    || Tolerance::Relative(relative).is_negligible(value, scale)
+            }
+            Tolerance::Ulps(ulps) => ulps_eq(scale.abs() + value.abs(), scale.abs(), ulps),
+        }
+    }
+}
+
+/// How `Vector::dot_with`, `magnitude_with` and `normalize_with` add up terms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Summation {
+    /// Left to right, the error grows linearly with the length.
+    #[default]
+    Naive,
+    /// Neumaier's variant of Kahan summation, the error doesn't grow with the length.
+    Compensated,
+    /// Recursive halves, the error grows with the logarithm of the length.
+    Pairwise,
+    /// Compensated, after dividing by the largest magnitude so squares and
+    /// products neither overflow nor underflow.
+    Scaled,
+}
+
+/// Below this many terms `Summation::Pairwise` adds them left to right.
+const PAIRWISE_BLOCK: usize = 8;
+
+impl Summation {
+    pub fn sum(&self, values: impl IntoIterator<Item = f64>) -> f64 {
+        match self {
+            Summation::Naive => values.into_iter().fold(0., |sum, value| sum + value),
+            Summation::Compensated | Summation::Scaled => compensated_sum(values),
+            Summation::Pairwise => pairwise_sum(&values.into_iter().collect::<Vec<_>>()),
+        }
+    }
+}
+
+fn compensated_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    let (sum, compensation) =
+        values
+            .into_iter()
+            .fold((0., 0.), |(sum, compensation): (f64, f64), value| {
+                let next = sum + value;
+                // Past an overflow or an infinite term the lost part is `inf - inf`.
+                let lost = if !next.is_finite() {
+                    0.
+                } else if sum.abs() >= value.abs() {
+                    (sum - next) + value
+                } else {
+                    (value - next) + sum
+                };
+                (next, compensation + lost)
+            });
+    sum + compensation
+}
+
+fn pairwise_sum(values: &[f64]) -> f64 {
+    if values.len() <= PAIRWISE_BLOCK {
+        return values.iter().fold(0., |sum, value| sum + value);
+    }
+    let (left, right) = values.split_at(values.len() / 2);
+    pairwise_sum(left) + pairwise_sum(right)
+}
+
+fn ulps_eq(value: f64, target: f64, ulps: u32) -> bool {
+    if value == target {
+        return true;
+    }
+    if value.is_nan() || target.is_nan() || value.is_sign_positive() != target.is_sign_positive() {
+        return false;
+    }
+    value.to_bits().abs_diff(target.to_bits()) <= ulps as u64
+}
+
+pub(crate) fn max_abs(iter: impl IntoIterator<Item = f64>) -> f64 {
+    iter.into_iter().map(f64::abs).fold(0., f64::max)
+}
+
 pub fn to_rad(deg: f64) -> f64 {
     deg * PI / 180.
 }
@@ -45,10 +172,66 @@ pub fn first_nonzero_index(iter: impl IntoIterator<Item = f64>) -> Result<usize,
     Err(String::from(NO_NONZERO_ELEMENTS_FOUND))
 }
 
+/// Like `first_nonzero_index`, items are compared against the largest magnitude among them.
+pub fn first_nonzero_index_with(
+    iter: impl IntoIterator<Item = f64>,
+    tolerance: &Tolerance,
+) -> Result<usize, String> {
+    let items: Vec<f64> = iter.into_iter().collect();
+    let scale = max_abs(items.iter().copied());
+    first_nonzero_index_relative_to(items, scale, tolerance)
+}
+
+pub(crate) fn first_nonzero_index_relative_to(
+    iter: impl IntoIterator<Item = f64>,
+    scale: f64,
+    tolerance: &Tolerance,
+) -> Result<usize, String> {
+    for (index, item) in iter.into_iter().enumerate() {
+        if !tolerance.is_negligible(item, scale) {
+            return Ok(index);
+        }
+    }
+    Err(String::from(NO_NONZERO_ELEMENTS_FOUND))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::math::*;
 
+    #[test]
+    fn summation() {
+        let values = [1e16, 1., -1e16];
+        assert_eq!(Summation::Naive.sum(values), 0.);
+        assert_eq!(Summation::Compensated.sum(values), 1.);
+        assert_eq!(Summation::Scaled.sum(values), 1.);
+        assert_eq!(Summation::Pairwise.sum([]), 0.);
+
+        let tenths = vec![0.1; 1_000_000];
+        let naive = Summation::Naive.sum(tenths.iter().copied());
+        let pairwise = Summation::Pairwise.sum(tenths.iter().copied());
+        let compensated = Summation::Compensated.sum(tenths.iter().copied());
+        assert!((naive - 1e5).abs() > 1e-7);
+        assert!((pairwise - 1e5).abs() < 1e-9);
+        assert!((compensated - 1e5).abs() < 1e-9);
+
+        assert_eq!(
+            Summation::Compensated.sum([f64::INFINITY, 1.]),
+            f64::INFINITY
+        );
+        assert_eq!(
+            Summation::Compensated.sum([1., f64::NEG_INFINITY]),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(
+            Summation::Compensated.sum([f64::MAX, f64::MAX]),
+            f64::INFINITY
+        );
+        assert!(Summation::Compensated
+            .sum([f64::INFINITY, f64::NEG_INFINITY])
+            .is_nan());
+    }
+
     #[test]
     fn deg_to_rad() {
         assert_eq!(to_rad(90.), PI / 2.);
@@ -90,4 +273,58 @@ mod tests {
             Err(String::from(NO_NONZERO_ELEMENTS_FOUND))
         );
     }
+
+    #[test]
+    fn first_nonzero_index_with_case() {
+        let tolerance = Tolerance::Relative(1e-10);
+        assert_eq!(
+            first_nonzero_index_with([1e-12, 1e-20, 2e-12], &tolerance),
+            Ok(0)
+        );
+        assert_eq!(first_nonzero_index_with([1e-25, 1e-12], &tolerance), Ok(1));
+        assert_eq!(first_nonzero_index_with([1e-3, 1e8], &tolerance), Ok(1));
+        assert_eq!(
+            first_nonzero_index_with([1e-3, 1e8], &Tolerance::Relative(1e-12)),
+            Ok(0)
+        );
+        assert_eq!(
+            first_nonzero_index_with([0., 0.], &tolerance),
+            Err(String::from(NO_NONZERO_ELEMENTS_FOUND))
+        );
+    }
+
+    #[test]
+    fn tolerance_eq() {
+        assert!(Tolerance::default().approx_eq(1., 1. + 1e-11));
+        assert!(!Tolerance::default().approx_eq(1e20, 1e20 * (1. + 1e-15)));
+
+        let relative = Tolerance::Relative(1e-12);
+        assert!(relative.approx_eq(1e20, 1e20 * (1. + 1e-15)));
+        assert!(!relative.approx_eq(1e-20, 2e-20));
+        assert!(!relative.approx_eq(1e-20, 0.));
+
+        let combined = Tolerance::Combined {
+            absolute: 1e-10,
+            relative: 1e-12,
+        };
+        assert!(combined.approx_eq(1e-20, 0.));
+        assert!(combined.approx_eq(1e20, 1e20 * (1. + 1e-15)));
+        assert!(combined.approx_ne(1., 1.1));
+
+        let ulps = Tolerance::Ulps(4);
+        assert!(ulps.approx_eq(0.1 + 0.2, 0.3));
+        assert!(ulps.approx_eq(0., -0.));
+        assert!(!ulps.approx_eq(1., 1. + 1e-10));
+        assert!(!ulps.approx_eq(f64::NAN, f64::NAN));
+    }
+
+    #[test]
+    fn tolerance_is_negligible() {
+        assert!(Tolerance::default().is_negligible(1e-11, 1e-12));
+        assert!(!Tolerance::default().is_negligible(1e-3, 1e9));
+        assert!(Tolerance::Relative(1e-10).is_negligible(1e-3, 1e9));
+        assert!(!Tolerance::Relative(1e-10).is_negligible(1e-11, 1e-12));
+        assert!(Tolerance::Ulps(1).is_negligible(1e-17, 1.));
+        assert!(!Tolerance::Ulps(1).is_negligible(1e-10, 1.));
+    }
 }
@@ -1,8 +1,25 @@
 mod fmt;
 mod iter;
+mod multiply;
+mod norm;
 mod ops;
+mod parse;
+mod strassen;
 
-use crate::{round::round_factory, vector, Vector};
+pub use fmt::Compact;
+pub use norm::Norm;
+pub use strassen::STRASSEN_CUTOFF;
+
+#[cfg(test)]
+pub(crate) use multiply::fill;
+
+use crate::{
+    linear_system::{reduction::Reduction, LinearSystem, Solutions},
+    math::Tolerance,
+    parallel,
+    round::round_factory,
+    vector, Vector,
+};
 
 const MATRIX_IS_NOT_INVERTIBLE: &str = "Matrix is not invertible.";
 
@@ -19,7 +36,31 @@ pub fn matrix<const ROW: usize, const COL: usize>(vectors: [[f64; COL]; ROW]) ->
     Matrix::from(vectors)
 }
 
+/// `matrix![2, 1, 3; 5, 7, 8]`, the elements are converted with `as f64`.
+/// Rows of different lengths don't compile.
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        $crate::matrix([])
+    };
+    ($($($x:expr),+);+ $(;)?) => {
+        $crate::matrix([$([$(($x) as f64),+]),+])
+    };
+}
+
 impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn from_rows(rows: [Vector<COL>; ROW]) -> Self {
+        Matrix(rows)
+    }
+
+    pub fn from_cols(cols: [Vector<ROW>; COL]) -> Self {
+        Matrix(cols).transpose()
+    }
+
+    pub(crate) fn as_mut_rows(&mut self) -> &mut [Vector<COL>] {
+        &mut self.0
+    }
+
     pub fn row(&self) -> usize {
         ROW
     }
@@ -28,6 +69,8 @@ impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
         COL
     }
 
+    /// Always serial: `f` isn't required to be `Send + Sync`, so closures over
+    /// `Cell` or `Rc` keep working. Use `par_map` for the parallel version.
     pub fn map<F>(&self, f: F) -> Self
     where
         F: Fn(f64) -> f64,
@@ -35,6 +78,17 @@ impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
         self.into_iter().map(|vector| vector.map(&f)).collect()
     }
 
+    /// `map` split across threads by rows once the matrix is large enough.
+    #[cfg(feature = "rayon")]
+    pub fn par_map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync,
+    {
+        let mut mapped = *self;
+        parallel::for_each_row(&mut mapped.0, ROW * COL, |_, row| *row = row.map(&f));
+        mapped
+    }
+
     pub fn round(&self, precision: usize) -> Self {
         let round = round_factory(precision);
         self.map(round)
@@ -44,6 +98,22 @@ impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
         self.map(|x| x * scalar)
     }
 
+    /// Element-wise product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.into_iter()
+            .zip(*other)
+            .map(|(row, other_row)| row.hadamard(&other_row))
+            .collect()
+    }
+
+    /// Element-wise quotient.
+    pub fn component_div(&self, other: &Self) -> Self {
+        self.into_iter()
+            .zip(*other)
+            .map(|(row, other_row)| row.component_div(&other_row))
+            .collect()
+    }
+
     pub fn get_row(&self, row: usize) -> Vector<COL> {
         self[row]
     }
@@ -52,25 +122,47 @@ impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
         self.into_iter().map(|vector| vector[col]).collect()
     }
 
-    pub fn multiply<const OTHER_COL: usize>(
-        &self,
-        other: &Matrix<COL, OTHER_COL>,
-    ) -> Matrix<ROW, OTHER_COL> {
-        let transpose_other = other.transpose();
-        self.into_iter()
-            .map(|row| {
-                transpose_other
-                    .into_iter()
-                    .map(|other_col| row * other_col)
-                    .collect()
-            })
-            .collect()
+    pub fn multiply_vector(&self, vector: &Vector<COL>) -> Vector<ROW> {
+        self.into_iter().map(|row| row * *vector).collect()
     }
 
     pub fn transpose(&self) -> Matrix<COL, ROW> {
-        (0..self.col())
-            .map(|col| (0..self.row()).map(|row| self[row][col]).collect())
-            .collect()
+        let mut transpose = Matrix([vector([0.; ROW]); COL]);
+        parallel::for_each_row(&mut transpose.0, ROW * COL, |col, transpose_row| {
+            (0..ROW).for_each(|row| transpose_row[row] = self[row][col])
+        });
+        transpose
+    }
+
+    fn to_homogeneous_system(self) -> LinearSystem<COL, ROW> {
+        LinearSystem::from_augmented(self, vector([0.; ROW]))
+    }
+
+    pub fn row_echelon(&self) -> Self {
+        self.to_homogeneous_system()
+            .compute_triangular_form()
+            .coefficient_matrix()
+    }
+
+    // Reduced Row-Echelon Form
+    pub fn rref(&self) -> Self {
+        self.to_homogeneous_system()
+            .compute_rref()
+            .coefficient_matrix()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank_with(&Tolerance::default())
+    }
+
+    /// Rank with pivots decided by `tolerance` relative to the largest entry.
+    pub fn rank_with(&self, tolerance: &Tolerance) -> usize {
+        Reduction::triangular_form(&self.to_homogeneous_system(), tolerance).rank()
+    }
+
+    /// Solve `self * X = constants` for every column of `constants` at once.
+    pub fn solve<const K: usize>(&self, constants: &Matrix<ROW, K>) -> Solutions<COL, K> {
+        self.to_homogeneous_system().compute_solutions(constants)
     }
 }
 
@@ -80,6 +172,20 @@ pub fn identity<const N: usize>() -> Matrix<N, N> {
         .collect()
 }
 
+/// A column vector. Row vectors have no `From` impls since they'd overlap with
+/// these at `N = 1`, use `to_row_matrix` and `Vector::from_row_matrix`.
+impl<const N: usize> From<Vector<N>> for Matrix<N, 1> {
+    fn from(vector: Vector<N>) -> Self {
+        vector.to_col_matrix()
+    }
+}
+
+impl<const N: usize> From<Matrix<N, 1>> for Vector<N> {
+    fn from(matrix: Matrix<N, 1>) -> Self {
+        matrix.get_col(0)
+    }
+}
+
 impl Matrix<1, 1> {
     pub fn inverse(&self) -> Self {
         matrix([[1. / self[0][0]]])
@@ -106,8 +212,76 @@ impl Matrix<2, 2> {
 
 #[cfg(test)]
 mod tests {
+    use crate::math::Tolerance;
     use crate::matrix;
     use crate::matrix::MATRIX_IS_NOT_INVERTIBLE;
+    use crate::{vector, Matrix, Vector};
+
+    #[test]
+    fn from_rows() {
+        let m = matrix([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(
+            Matrix::from_rows([vector([1., 2., 3.]), vector([4., 5., 6.])]),
+            m
+        );
+        assert_eq!(
+            Matrix::from_cols([vector([1., 4.]), vector([2., 5.]), vector([3., 6.])]),
+            m
+        );
+    }
+
+    #[test]
+    fn vector_conversion() {
+        let v = vector([1., 2., 3.]);
+        let m: Matrix<3, 1> = v.into();
+        assert_eq!(m, matrix([[1.], [2.], [3.]]));
+        assert_eq!(Vector::from(m), v);
+        assert_eq!(v.to_row_matrix(), matrix([[1., 2., 3.]]));
+        assert_eq!(Vector::from_row_matrix(&v.to_row_matrix()), v);
+    }
+
+    #[test]
+    fn hadamard() {
+        let a = matrix([[1., 2.], [3., 4.]]);
+        let b = matrix([[2., 4.], [-1., 8.]]);
+        assert_eq!(a.hadamard(&b), matrix([[2., 8.], [-3., 32.]]));
+        assert_eq!(a.component_div(&b), matrix([[0.5, 0.5], [-3., 0.5]]));
+        assert_eq!(
+            vector([1., 2.]).hadamard(&vector([3., 4.])),
+            vector([3., 8.])
+        );
+        assert_eq!(
+            vector([1., 2.]).component_div(&vector([4., 4.])),
+            vector([0.25, 0.5])
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_map() {
+        // Large enough to pass the parallel threshold, which needs a bigger stack.
+        std::thread::Builder::new()
+            .stack_size(32 << 20)
+            .spawn(|| {
+                let m: Matrix<192, 192> = matrix(std::array::from_fn(|row| {
+                    std::array::from_fn(|col| (row * col) as f64)
+                }));
+                assert_eq!(m.par_map(f64::sqrt), m.map(f64::sqrt));
+
+                let m: Matrix<192, 192> = matrix(std::array::from_fn(|row| {
+                    std::array::from_fn(|col| (row * 192 + col) as f64)
+                }));
+                let transpose = m.transpose();
+                for row in 0..192 {
+                    for col in 0..192 {
+                        assert_eq!(transpose[col][row], (row * 192 + col) as f64);
+                    }
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
 
     #[test]
     fn transpose() {
@@ -127,6 +301,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn row_echelon() {
+        let m = matrix([[1., 1., 1.], [0., 1., 0.], [1., 1., -1.], [1., 0., -2.]]);
+        assert_eq!(
+            m.row_echelon().round(3),
+            matrix([[1., 1., 1.], [0., 1., 0.], [0., 0., -2.], [0., 0., 0.]])
+        );
+
+        let m = matrix([[0., 1., 1.], [1., -1., 1.], [1., 2., -5.]]);
+        assert_eq!(
+            m.row_echelon().round(3),
+            matrix([[1., -1., 1.], [0., 1., 1.], [0., 0., -9.]])
+        );
+    }
+
+    #[test]
+    fn rref() {
+        let m = matrix([[1., 1., 1.], [0., 1., 1.]]);
+        assert_eq!(m.rref().round(3), matrix([[1., 0., 0.], [0., 1., 1.]]));
+
+        let m = matrix([[0., 1., 1.], [1., -1., 1.], [1., 2., -5.]]);
+        assert_eq!(m.rref().round(3), matrix::identity());
+    }
+
+    #[test]
+    fn rank() {
+        assert_eq!(matrix::<0, 0>([]).rank(), 0);
+        assert_eq!(matrix([[0., 0.], [0., 0.]]).rank(), 0);
+        assert_eq!(matrix([[4., 2.], [14., 7.]]).rank(), 1);
+        assert_eq!(matrix([[1., 1., 1.], [1., 1., 1.], [0., 0., 1.]]).rank(), 2);
+        assert_eq!(
+            matrix([[1., 1., 1.], [0., 1., 0.], [1., 1., -1.], [1., 0., -2.]]).rank(),
+            3
+        );
+    }
+
+    #[test]
+    fn rank_with() {
+        let m = matrix([[1e-12, 2e-12], [1e-12, 3e-12]]);
+        assert_eq!(m.rank(), 0);
+        assert_eq!(m.rank_with(&Tolerance::Relative(1e-10)), 2);
+
+        let m = matrix([[1e12, 1e12], [1e12, 1e12 + 1e-3]]);
+        assert_eq!(m.rank(), 2);
+        assert_eq!(m.rank_with(&Tolerance::Relative(1e-10)), 1);
+    }
+
+    #[test]
+    fn solve() {
+        let m = matrix([[4., 5.], [7., 1.]]);
+        let solutions = m.solve(&matrix::identity());
+        assert_eq!(solutions.values.round(3), m.inverse().unwrap().round(3));
+
+        let m = matrix([[5., 3., 1.], [6., 2., 7.], [1., 1., 1.]]);
+        let x = matrix([[1., 2.], [3., -1.], [0.5, 4.]]);
+        assert_eq!(m.solve(&(m * x)).values.round(3), x);
+    }
+
     #[test]
     fn identity() {
         assert_eq!(matrix::identity(), matrix([[1.]]));
@@ -166,4 +398,14 @@ mod tests {
             Err(String::from(MATRIX_IS_NOT_INVERTIBLE))
         );
     }
+
+    #[test]
+    fn matrix_macro() {
+        assert_eq!(
+            crate::matrix![2, 1, 3; 5, 7.5, -8],
+            matrix([[2., 1., 3.], [5., 7.5, -8.]])
+        );
+        assert_eq!(crate::matrix![1; 2;], matrix([[1.], [2.]]));
+        assert_eq!(crate::matrix![], matrix::<0, 0>([]));
+    }
 }
@@ -1,14 +1,109 @@
-use std::fmt::Display;
+use std::fmt::{Display, Formatter, LowerExp, Result};
 
 use crate::Matrix;
 
+/// Rows or columns past this count are elided, keeping `EDGE` on each side.
+const ELIDE_AFTER: usize = 10;
+const EDGE: usize = 4;
+const ELLIPSIS: &str = "...";
+
+/// The one line per row format of `Vector`, reachable through `Matrix::compact`.
+pub struct Compact<'a, const ROW: usize, const COL: usize>(&'a Matrix<ROW, COL>);
+
+impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn compact(&self) -> Compact<'_, ROW, COL> {
+        Compact(self)
+    }
+
+    /// Shows every cell with a shared width, `format` writes a single value.
+    fn write_aligned(&self, f: &mut Formatter<'_>, format: impl Fn(f64) -> String) -> Result {
+        if ROW == 0 || COL == 0 {
+            return write!(f, "[]");
+        }
+
+        let rows: Vec<Vec<String>> = visible(ROW)
+            .into_iter()
+            .map(|row| {
+                visible(COL)
+                    .into_iter()
+                    .map(|col| match (row, col) {
+                        (Some(row), Some(col)) => format(self[row][col]),
+                        _ => String::from(ELLIPSIS),
+                    })
+                    .collect()
+            })
+            .collect();
+        let width = rows
+            .iter()
+            .flatten()
+            .map(|cell| cell.chars().count())
+            .chain(f.width())
+            .max()
+            .unwrap_or(0);
+
+        let last = rows.len() - 1;
+        for (index, row) in rows.iter().enumerate() {
+            let (left, right) = match (f.alternate(), index) {
+                (true, _) => ("[", "]"),
+                (false, _) if last == 0 => ("[", "]"),
+                (false, 0) => ("⎡", "⎤"),
+                (false, index) if index == last => ("⎣", "⎦"),
+                (false, _) => ("⎢", "⎥"),
+            };
+            write!(f, "{}", left)?;
+            for (col, cell) in row.iter().enumerate() {
+                if col != 0 {
+                    write!(f, "  ")?;
+                }
+                write!(f, "{:>width$}", cell, width = width)?;
+            }
+            writeln!(f, "{}", right)?;
+        }
+        Ok(())
+    }
+}
+
+/// Indices to show, `None` marks the elided run.
+fn visible(len: usize) -> Vec<Option<usize>> {
+    if len > ELIDE_AFTER {
+        (0..EDGE)
+            .map(Some)
+            .chain([None])
+            .chain((len - EDGE..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+/// Aligned columns in Unicode brackets, `{:#}` uses ASCII brackets on every row.
 impl<const ROW: usize, const COL: usize> Display for Matrix<ROW, COL> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision = f.precision();
+        self.write_aligned(f, |value| match precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => format!("{}", value),
+        })
+    }
+}
+
+impl<const ROW: usize, const COL: usize> LowerExp for Matrix<ROW, COL> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision = f.precision();
+        self.write_aligned(f, |value| match precision {
+            Some(precision) => format!("{:.*e}", precision, value),
+            None => format!("{:e}", value),
+        })
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Display for Compact<'_, ROW, COL> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         if ROW == 0 {
             return write!(f, "[]");
         }
 
-        for vector in self.into_iter() {
+        for vector in self.0.into_iter() {
             vector.fmt(f)?
         }
         Ok(())
@@ -17,18 +112,58 @@ impl<const ROW: usize, const COL: usize> Display for Matrix<ROW, COL> {
 
 #[cfg(test)]
 mod tests {
-    use crate::matrix;
+    use crate::{matrix, Matrix};
 
     #[test]
     fn fmt() {
         assert_eq!(format!("{}", matrix::<0, 0>([])), "[]");
-        assert_eq!(format!("{}", matrix([[2., 1., 3.]])), "[2, 1, 3]\n");
+        assert_eq!(format!("{}", matrix([[2., 1., 3.]])), "[2  1  3]\n");
+        assert_eq!(
+            format!("{}", matrix([[2., 1., 3.], [5., 7., -8.]])),
+            "⎡ 2   1   3⎤\n⎣ 5   7  -8⎦\n"
+        );
+        assert_eq!(
+            format!("{:.1}", matrix([[2.53, 1.], [-1.7823, 3.], [0., 10.]])),
+            "⎡ 2.5   1.0⎤\n⎢-1.8   3.0⎥\n⎣ 0.0  10.0⎦\n"
+        );
+        assert_eq!(
+            format!("{:#}", matrix([[2., 1.], [5., 17.]])),
+            "[ 2   1]\n[ 5  17]\n"
+        );
+        assert_eq!(format!("{:3}", matrix([[2., 1.]])), "[  2    1]\n");
+    }
+
+    #[test]
+    fn fmt_exp() {
+        assert_eq!(
+            format!("{:e}", matrix([[1200., 0.5], [-3., 1.]])),
+            "⎡1.2e3   5e-1⎤\n⎣ -3e0    1e0⎦\n"
+        );
+        assert_eq!(format!("{:.1e}", matrix([[1234.]])), "[1.2e3]\n");
+    }
+
+    #[test]
+    fn fmt_elided() {
+        let m: Matrix<12, 12> = matrix(std::array::from_fn(|row| {
+            std::array::from_fn(|col| (row * 12 + col) as f64)
+        }));
+        let text = format!("{:#}", m);
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "[  0    1    2    3  ...    8    9   10   11]");
+        assert_eq!(lines[4], "[...  ...  ...  ...  ...  ...  ...  ...  ...]");
+        assert_eq!(lines[8], "[132  133  134  135  ...  140  141  142  143]");
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(format!("{}", matrix::<0, 0>([]).compact()), "[]");
         assert_eq!(
-            format!("{}", matrix([[2., 1., 3.], [5., 7., 8.]])),
+            format!("{}", matrix([[2., 1., 3.], [5., 7., 8.]]).compact()),
             "[2, 1, 3]\n[5, 7, 8]\n"
         );
         assert_eq!(
-            format!("{:.1}", matrix([[2.53, 1.7823, 3.161]])),
+            format!("{:.1}", matrix([[2.53, 1.7823, 3.161]]).compact()),
             "[2.5, 1.8, 3.2]\n"
         );
     }
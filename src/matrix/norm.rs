@@ -0,0 +1,202 @@
+use crate::{
+    linear_system::{reduction::Reduction, SolutionKind},
+    math::Tolerance,
+    matrix, vector, Matrix, Vector,
+};
+
+use super::identity;
+
+const MAX_JACOBI_SWEEPS: usize = 100;
+const MAX_HAGER_ITERATIONS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Norm {
+    One,
+    Two,
+    Infinity,
+}
+
+impl<const ROW: usize, const COL: usize> Matrix<ROW, COL> {
+    pub fn norm(&self, norm: Norm) -> f64 {
+        match norm {
+            Norm::One => self.transpose().max_abs_row_sum(),
+            Norm::Two => largest(self.gram().symmetric_eigenvalues()).sqrt(),
+            Norm::Infinity => self.max_abs_row_sum(),
+        }
+    }
+
+    fn max_abs_row_sum(&self) -> f64 {
+        self.into_iter()
+            .map(|row| row.into_iter().map(f64::abs).sum::<f64>())
+            .fold(0., f64::max)
+    }
+
+    /// `transpose(self) * self`
+    pub(crate) fn gram(&self) -> Matrix<COL, COL> {
+        self.transpose() * *self
+    }
+}
+
+impl<const N: usize> Matrix<N, N> {
+    /// Condition number `‖A‖ ‖A⁻¹‖` in the given norm, `f64::INFINITY` for singular matrices.
+    pub fn condition_number(&self, norm: Norm) -> f64 {
+        match norm {
+            Norm::Two => {
+                let eigenvalues = self.gram().symmetric_eigenvalues();
+                let smallest = eigenvalues.into_iter().fold(f64::INFINITY, f64::min);
+                if smallest <= 0. {
+                    return f64::INFINITY;
+                }
+                (largest(eigenvalues) / smallest).sqrt()
+            }
+            Norm::One | Norm::Infinity => {
+                let inverse = self.solve(&identity());
+                if inverse.kinds.iter().any(|kind| *kind != SolutionKind::Some) {
+                    return f64::INFINITY;
+                }
+                self.norm(norm) * inverse.values.norm(norm)
+            }
+        }
+    }
+
+    /// Hager's estimate of the 1-norm condition number, it only needs a
+    /// single reduction of the matrix and its transpose.
+    pub fn estimate_condition_number(&self) -> f64 {
+        let tolerance = Tolerance::default();
+        let reduction = Reduction::rref(&self.to_homogeneous_system(), &tolerance);
+        let transpose_reduction =
+            Reduction::rref(&self.transpose().to_homogeneous_system(), &tolerance);
+        if reduction.rank() < N {
+            return f64::INFINITY;
+        }
+
+        let mut x = vector([1. / N as f64; N]);
+        let mut inverse_norm = 0.;
+        for _ in 0..MAX_HAGER_ITERATIONS {
+            let y = reduction.replay_vector(&x);
+            inverse_norm = y.into_iter().map(f64::abs).sum::<f64>();
+            let signs = y.map(|y| if y < 0. { -1. } else { 1. });
+            let z = transpose_reduction.replay_vector(&signs);
+            let (index, max) = z
+                .into_iter()
+                .map(f64::abs)
+                .enumerate()
+                .fold((0, 0.), |max, item| if item.1 > max.1 { item } else { max });
+            if max <= z.dot(&x) {
+                break;
+            }
+            x = vector(std::array::from_fn(|i| if i == index { 1. } else { 0. }));
+        }
+
+        self.norm(Norm::One) * inverse_norm
+    }
+
+    /// Eigenvalues of a symmetric matrix by the cyclic Jacobi method.
+    fn symmetric_eigenvalues(&self) -> Vector<N> {
+        let mut a: [[f64; N]; N] =
+            std::array::from_fn(|row| std::array::from_fn(|col| self[row][col]));
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off_diagonal = (0..N)
+                .flat_map(|p| (p + 1..N).map(move |q| (p, q)))
+                .map(|(p, q)| a[p][q].powi(2))
+                .sum::<f64>();
+            if off_diagonal == 0. {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[p][q] == 0. {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta.powi(2) + 1.).sqrt());
+                    let c = 1. / (t.powi(2) + 1.).sqrt();
+                    let s = t * c;
+                    for row in a.iter_mut() {
+                        let (akp, akq) = (row[p], row[q]);
+                        row[p] = c * akp - s * akq;
+                        row[q] = s * akp + c * akq;
+                    }
+                    let (row_p, row_q) = (a[p], a[q]);
+                    for k in 0..N {
+                        a[p][k] = c * row_p[k] - s * row_q[k];
+                        a[q][k] = s * row_p[k] + c * row_q[k];
+                    }
+                }
+            }
+        }
+
+        matrix(a).diagonal()
+    }
+
+    fn diagonal(&self) -> Vector<N> {
+        (0..N).map(|i| self[i][i]).collect()
+    }
+}
+
+fn largest<const N: usize>(values: Vector<N>) -> f64 {
+    values.into_iter().fold(0., f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, round::round_factory, vector};
+
+    use super::Norm;
+
+    #[test]
+    fn norm() {
+        let round = round_factory(3);
+        let m = matrix([[1., -2.], [-3., 4.]]);
+        assert_eq!(m.norm(Norm::One), 6.);
+        assert_eq!(m.norm(Norm::Infinity), 7.);
+        assert_eq!(round(m.norm(Norm::Two)), 5.465);
+
+        let m = matrix([[5., 3., 1.], [6., 2., 7.]]);
+        assert_eq!(m.norm(Norm::One), 11.);
+        assert_eq!(m.norm(Norm::Infinity), 15.);
+    }
+
+    #[test]
+    fn symmetric_eigenvalues() {
+        let m = matrix([[2., 1.], [1., 2.]]);
+        assert_eq!(m.symmetric_eigenvalues().round(3), vector([1., 3.]));
+
+        let m = matrix([[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]]);
+        let eigenvalues = m.symmetric_eigenvalues();
+        let round = round_factory(6);
+        assert_eq!(round(eigenvalues.into_iter().sum::<f64>()), 9.);
+    }
+
+    #[test]
+    fn condition_number() {
+        let round = round_factory(3);
+        let m = matrix([[1., 2.], [3., 4.]]);
+        assert_eq!(round(m.condition_number(Norm::One)), 21.);
+        assert_eq!(round(m.condition_number(Norm::Infinity)), 21.);
+        assert_eq!(round(m.condition_number(Norm::Two)), 14.933);
+
+        let m = matrix([[4., 2.], [14., 7.]]);
+        assert_eq!(m.condition_number(Norm::One), f64::INFINITY);
+        assert_eq!(m.condition_number(Norm::Infinity), f64::INFINITY);
+
+        let m = matrix::identity::<3>();
+        assert_eq!(m.condition_number(Norm::Two), 1.);
+    }
+
+    #[test]
+    fn estimate_condition_number() {
+        let round = round_factory(3);
+        let m = matrix([[1., 2.], [3., 4.]]);
+        assert_eq!(round(m.estimate_condition_number()), 21.);
+
+        let m = matrix([[5., 3., 1.], [6., 2., 7.], [1., 1., 1.]]);
+        assert!(m.estimate_condition_number() <= m.condition_number(Norm::One) * 1.000001);
+
+        let m = matrix([[4., 2.], [14., 7.]]);
+        assert_eq!(m.estimate_condition_number(), f64::INFINITY);
+    }
+}
@@ -1,6 +1,8 @@
-use std::ops::{Add, Index, Mul, Sub};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-use crate::{Matrix, Vector};
+use crate::{vector, Matrix, Vector};
 
 impl<const ROW: usize, const COL: usize> Index<usize> for Matrix<ROW, COL> {
     type Output = Vector<COL>;
@@ -10,13 +12,55 @@ impl<const ROW: usize, const COL: usize> Index<usize> for Matrix<ROW, COL> {
     }
 }
 
+impl<const ROW: usize, const COL: usize> IndexMut<usize> for Matrix<ROW, COL> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// `m[(row, col)]` for a single element.
+impl<const ROW: usize, const COL: usize> Index<(usize, usize)> for Matrix<ROW, COL> {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.0[row][col]
+    }
+}
+
+impl<const ROW: usize, const COL: usize> IndexMut<(usize, usize)> for Matrix<ROW, COL> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[row][col]
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Add for &Matrix<ROW, COL> {
+    type Output = Matrix<ROW, COL>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.0
+            .iter()
+            .zip(&rhs.0)
+            .map(|(row, other)| row + other)
+            .collect()
+    }
+}
+
 impl<const ROW: usize, const COL: usize> Add for Matrix<ROW, COL> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.into_iter()
-            .enumerate()
-            .map(|(index, _)| self[index] + rhs[index])
+        Add::add(&self, &rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Sub for &Matrix<ROW, COL> {
+    type Output = Matrix<ROW, COL>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0
+            .iter()
+            .zip(&rhs.0)
+            .map(|(row, other)| row - other)
             .collect()
     }
 }
@@ -25,10 +69,15 @@ impl<const ROW: usize, const COL: usize> Sub for Matrix<ROW, COL> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.into_iter()
-            .enumerate()
-            .map(|(index, _)| self[index] - rhs[index])
-            .collect()
+        Sub::sub(&self, &rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<f64> for &Matrix<ROW, COL> {
+    type Output = Matrix<ROW, COL>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scale(rhs)
     }
 }
 
@@ -36,7 +85,118 @@ impl<const ROW: usize, const COL: usize> Mul<f64> for Matrix<ROW, COL> {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        self.scale(rhs)
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<&Matrix<ROW, COL>> for f64 {
+    type Output = Matrix<ROW, COL>;
+
+    fn mul(self, rhs: &Matrix<ROW, COL>) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<Matrix<ROW, COL>> for f64 {
+    type Output = Matrix<ROW, COL>;
+
+    fn mul(self, rhs: Matrix<ROW, COL>) -> Self::Output {
+        Mul::mul(self, &rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Div<f64> for &Matrix<ROW, COL> {
+    type Output = Matrix<ROW, COL>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(|x| x / rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Div<f64> for Matrix<ROW, COL> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Div::div(&self, rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Neg for &Matrix<ROW, COL> {
+    type Output = Matrix<ROW, COL>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Neg for Matrix<ROW, COL> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<const ROW: usize, const COL: usize> AddAssign<&Matrix<ROW, COL>> for Matrix<ROW, COL> {
+    fn add_assign(&mut self, rhs: &Matrix<ROW, COL>) {
+        self.0
+            .iter_mut()
+            .zip(&rhs.0)
+            .for_each(|(row, other)| *row += other);
+    }
+}
+
+impl<const ROW: usize, const COL: usize> AddAssign for Matrix<ROW, COL> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<const ROW: usize, const COL: usize> SubAssign<&Matrix<ROW, COL>> for Matrix<ROW, COL> {
+    fn sub_assign(&mut self, rhs: &Matrix<ROW, COL>) {
+        self.0
+            .iter_mut()
+            .zip(&rhs.0)
+            .for_each(|(row, other)| *row -= other);
+    }
+}
+
+impl<const ROW: usize, const COL: usize> SubAssign for Matrix<ROW, COL> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<const ROW: usize, const COL: usize> MulAssign<f64> for Matrix<ROW, COL> {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0.iter_mut().for_each(|row| *row *= rhs);
+    }
+}
+
+/// `self = self * rhs`, only square right-hand sides keep the shape.
+impl<const ROW: usize, const COL: usize> MulAssign<&Matrix<COL, COL>> for Matrix<ROW, COL> {
+    fn mul_assign(&mut self, rhs: &Matrix<COL, COL>) {
+        *self = self.multiply(rhs);
+    }
+}
+
+impl<const ROW: usize, const COL: usize> MulAssign<Matrix<COL, COL>> for Matrix<ROW, COL> {
+    fn mul_assign(&mut self, rhs: Matrix<COL, COL>) {
+        *self *= &rhs;
+    }
+}
+
+impl<const ROW: usize, const COL: usize> DivAssign<f64> for Matrix<ROW, COL> {
+    fn div_assign(&mut self, rhs: f64) {
+        self.0.iter_mut().for_each(|row| *row /= rhs);
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize> Mul<&Matrix<N, P>> for &Matrix<M, N> {
+    type Output = Matrix<M, P>;
+
+    fn mul(self, rhs: &Matrix<N, P>) -> Self::Output {
+        self.multiply(rhs)
     }
 }
 
@@ -44,13 +204,52 @@ impl<const M: usize, const N: usize, const P: usize> Mul<Matrix<N, P>> for Matri
     type Output = Matrix<M, P>;
 
     fn mul(self, rhs: Matrix<N, P>) -> Self::Output {
-        self.multiply(&rhs)
+        Mul::mul(&self, &rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<&Vector<COL>> for &Matrix<ROW, COL> {
+    type Output = Vector<ROW>;
+
+    fn mul(self, rhs: &Vector<COL>) -> Self::Output {
+        self.multiply_vector(rhs)
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<Vector<COL>> for Matrix<ROW, COL> {
+    type Output = Vector<ROW>;
+
+    fn mul(self, rhs: Vector<COL>) -> Self::Output {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+/// The vector as a row vector, `vᵀ * A`, summing scaled rows so `A` isn't transposed.
+impl<const ROW: usize, const COL: usize> Mul<&Matrix<ROW, COL>> for &Vector<ROW> {
+    type Output = Vector<COL>;
+
+    fn mul(self, rhs: &Matrix<ROW, COL>) -> Self::Output {
+        let mut product = vector([0.; COL]);
+        for (scalar, row) in self.into_iter().zip(&rhs.0) {
+            for (out, value) in product.as_mut_slice().iter_mut().zip(row.as_slice()) {
+                *out += scalar * value;
+            }
+        }
+        product
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Mul<Matrix<ROW, COL>> for Vector<ROW> {
+    type Output = Vector<COL>;
+
+    fn mul(self, rhs: Matrix<ROW, COL>) -> Self::Output {
+        Mul::mul(&self, &rhs)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::matrix;
+    use crate::{matrix, vector};
 
     #[test]
     fn eq() {
@@ -73,6 +272,39 @@ mod tests {
     fn index() {
         let m = matrix([[2., 3., 0.], [4., 8., 1.]]);
         assert_eq!(m[1][0], 4.);
+        assert_eq!(m[(1, 1)], 8.);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut m = matrix([[2., 3.], [4., 8.]]);
+        m[0][1] = 5.;
+        m[(1, 0)] = -1.;
+        m[1] *= 2.;
+        assert_eq!(m, matrix([[2., 5.], [-2., 16.]]));
+    }
+
+    #[test]
+    fn neg_div() {
+        let m = matrix([[2., -4.]]);
+        assert_eq!(-m, matrix([[-2., 4.]]));
+        assert_eq!(m / 2., matrix([[1., -2.]]));
+        assert_eq!(3. * m, m * 3.);
+    }
+
+    #[test]
+    fn assign() {
+        let mut m = matrix([[1., 2.], [3., 4.]]);
+        m += matrix([[1., 1.], [1., 1.]]);
+        assert_eq!(m, matrix([[2., 3.], [4., 5.]]));
+        m -= matrix([[2., 2.], [2., 2.]]);
+        assert_eq!(m, matrix([[0., 1.], [2., 3.]]));
+        m *= 2.;
+        assert_eq!(m, matrix([[0., 2.], [4., 6.]]));
+        m /= 2.;
+        assert_eq!(m, matrix([[0., 1.], [2., 3.]]));
+        m *= matrix([[0., 1.], [1., 0.]]);
+        assert_eq!(m, matrix([[1., 0.], [3., 2.]]));
     }
 
     #[test]
@@ -183,4 +415,38 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn mul_vector() {
+        let m = matrix([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(m * vector([1., 0., -1.]), vector([-2., -2.]));
+        assert_eq!(vector([1., -1.]) * m, vector([-3., -3., -3.]));
+        assert_eq!(
+            vector([1., 2.]).outer(&vector([3., 4., 5.])),
+            matrix([[3., 4., 5.], [6., 8., 10.]])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference() {
+        let a = matrix([[1., 2.], [3., 4.]]);
+        let b = matrix([[0., 1.], [1., 0.]]);
+        let v = vector([1., -1.]);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a * 2., a * 2.);
+        assert_eq!(2. * &a, 2. * a);
+        assert_eq!(&a / 2., a / 2.);
+        assert_eq!(-&a, -a);
+        assert_eq!(&a * &v, a * v);
+        assert_eq!(&v * &a, v * a);
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        c *= &b;
+        assert_eq!(c, matrix([[1., 0.], [0., 1.]]));
+    }
 }
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{tokenize, ParseError, Parser, Spanned, Token},
+    vector, Matrix,
+};
+
+impl Parser<'_> {
+    /// `[1 2; 3 4]` or `[[1, 2], [3, 4]]`, rows start at their first token.
+    fn rows(&mut self, rows: &mut Vec<(Spanned, Vec<f64>)>) -> Result<(), ParseError> {
+        self.expect(Token::LeftBracket, "expected `[`")?;
+        if self.peek().token == Token::LeftBracket {
+            while self.peek().token != Token::RightBracket {
+                if !rows.is_empty() && self.peek().token == Token::Comma {
+                    self.next();
+                }
+                let start = self.expect(Token::LeftBracket, "expected `[`")?;
+                rows.push((start, self.row()?));
+                self.expect(Token::RightBracket, "expected `]`")?;
+            }
+        } else {
+            loop {
+                let start = self.peek();
+                let row = self.row()?;
+                if !row.is_empty() {
+                    rows.push((start, row));
+                }
+                if self.peek().token != Token::Semicolon {
+                    break;
+                }
+                self.next();
+            }
+        }
+        self.expect(Token::RightBracket, "expected `]`")?;
+        Ok(())
+    }
+}
+
+/// Rows as printed by `Display` or `compact`, one bracketed row per line, MATLAB-style
+/// `[2 1 3; 5 7 8]` or nested `[[2, 1, 3], [5, 7, 8]]`.
+impl<const ROW: usize, const COL: usize> FromStr for Matrix<ROW, COL> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s, 1, 1)?;
+        let mut parser = Parser::new(&tokens);
+        let mut rows = vec![];
+        loop {
+            parser.rows(&mut rows)?;
+            if parser.peek().token == Token::End {
+                break;
+            }
+        }
+
+        if rows.len() != ROW {
+            let position = rows.get(ROW).map_or(parser.peek(), |(start, _)| *start);
+            return Err(position.error(format!("expected {} rows, found {}", ROW, rows.len())));
+        }
+        let mut vectors = [vector([0.; COL]); ROW];
+        for (vector, (start, row)) in vectors.iter_mut().zip(rows) {
+            let len = row.len();
+            *vector =
+                crate::vector(row.try_into().map_err(|_| {
+                    start.error(format!("expected {} columns, found {}", COL, len))
+                })?);
+        }
+        Ok(vectors.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, parse::ParseError, Matrix};
+
+    #[test]
+    fn from_str() {
+        let m = matrix([[2., 1., 3.], [5., 7., -8.]]);
+        assert_eq!(format!("{}", m).parse(), Ok(m));
+        assert_eq!(format!("{:#}", m).parse(), Ok(m));
+        assert_eq!(format!("{}", m.compact()).parse(), Ok(m));
+        let column = matrix([[1.5], [-2.], [3e-4]]);
+        assert_eq!(format!("{}", column).parse(), Ok(column));
+        assert_eq!("[2 1 3; 5 7 -8]".parse(), Ok(m));
+        assert_eq!("[2, 1, 3;\n 5, 7, -8;]".parse(), Ok(m));
+        assert_eq!("[[2, 1, 3], [5, 7, -8]]".parse(), Ok(m));
+        assert_eq!("[]".parse(), Ok(matrix::<0, 0>([])));
+    }
+
+    #[test]
+    fn from_str_error() {
+        let parse = |s: &str| s.parse::<Matrix<2, 2>>();
+        assert_eq!(
+            parse("[1 2; 3]"),
+            Err(ParseError::new(1, 7, "expected 2 columns, found 1"))
+        );
+        assert_eq!(
+            parse("[1 2]"),
+            Err(ParseError::new(1, 6, "expected 2 rows, found 1"))
+        );
+        assert_eq!(
+            parse("[1, 2]\n[3, 4]\n[5, 6]\n"),
+            Err(ParseError::new(3, 2, "expected 2 rows, found 3"))
+        );
+        assert_eq!(
+            parse("[[1, 2], 3]"),
+            Err(ParseError::new(1, 10, "expected `[`"))
+        );
+        assert_eq!(
+            parse("[1 2; 3 4"),
+            Err(ParseError::new(1, 10, "expected `]`"))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{vector, Matrix};
+
+/// Size at or below which `Matrix::strassen` uses the standard product. The
+/// extra additions and buffers outweigh the saved multiplications below it.
+pub const STRASSEN_CUTOFF: usize = 256;
+
+impl<const N: usize> Matrix<N, N> {
+    pub fn strassen(&self, other: &Self) -> Self {
+        self.strassen_with(other, STRASSEN_CUTOFF)
+    }
+
+    /// Strassen's recursive product, blocks of `cutoff` or fewer rows use the
+    /// standard i-k-j product. Odd sizes are padded with a zero row and column.
+    ///
+    /// It needs `O(N^2.81)` multiplications but is only normwise stable:
+    /// `‖C - Ĉ‖ ≤ c(N) u ‖A‖ ‖B‖` with `c(N)` growing like `(N / cutoff)^3.6`,
+    /// while `multiply` is componentwise stable, `|C - Ĉ| ≤ N u |A| |B|`.
+    /// Entries much smaller than `‖A‖ ‖B‖` can lose relative accuracy, and the
+    /// result generally differs from `multiply` in the last bits.
+    pub fn strassen_with(&self, other: &Self, cutoff: usize) -> Self {
+        let cutoff = cutoff.max(1);
+        if N <= cutoff {
+            return self.multiply(other);
+        }
+
+        let product = strassen(&flatten(self), &flatten(other), N, cutoff);
+        Matrix::from_rows(std::array::from_fn(|row| {
+            vector(std::array::from_fn(|col| product[row * N + col]))
+        }))
+    }
+}
+
+/// Row-major entries.
+fn flatten<const N: usize>(matrix: &Matrix<N, N>) -> Vec<f64> {
+    matrix.into_iter().flatten().collect()
+}
+
+/// Product of two `n × n` row-major buffers.
+fn strassen(a: &[f64], b: &[f64], n: usize, cutoff: usize) -> Vec<f64> {
+    if n <= cutoff {
+        return standard(a, b, n);
+    }
+
+    let m = n.div_ceil(2);
+    let [a11, a12, a21, a22] = quadrants(a, n, m);
+    let [b11, b12, b21, b22] = quadrants(b, n, m);
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), m, cutoff);
+    let m2 = strassen(&add(&a21, &a22), &b11, m, cutoff);
+    let m3 = strassen(&a11, &sub(&b12, &b22), m, cutoff);
+    let m4 = strassen(&a22, &sub(&b21, &b11), m, cutoff);
+    let m5 = strassen(&add(&a11, &a12), &b22, m, cutoff);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), m, cutoff);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), m, cutoff);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = vec![0.; n * n];
+    for row in 0..n {
+        for col in 0..n {
+            let quadrant = match (row < m, col < m) {
+                (true, true) => &c11,
+                (true, false) => &c12,
+                (false, true) => &c21,
+                (false, false) => &c22,
+            };
+            c[row * n + col] = quadrant[(row % m) * m + col % m];
+        }
+    }
+    c
+}
+
+/// The four `m × m` quadrants of an `n × n` buffer, zero padded when `n` is odd.
+fn quadrants(x: &[f64], n: usize, m: usize) -> [Vec<f64>; 4] {
+    std::array::from_fn(|quadrant| {
+        let (row_offset, col_offset) = (quadrant / 2 * m, quadrant % 2 * m);
+        let mut q = vec![0.; m * m];
+        for row in 0..m.min(n - row_offset) {
+            for col in 0..m.min(n - col_offset) {
+                q[row * m + col] = x[(row_offset + row) * n + col_offset + col];
+            }
+        }
+        q
+    })
+}
+
+fn standard(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut c = vec![0.; n * n];
+    for row in 0..n {
+        for k in 0..n {
+            let scalar = a[row * n + k];
+            let out = &mut c[row * n..(row + 1) * n];
+            for (out, b) in out.iter_mut().zip(&b[k * n..(k + 1) * n]) {
+                *out += scalar * b;
+            }
+        }
+    }
+    c
+}
+
+fn add(x: &[f64], y: &[f64]) -> Vec<f64> {
+    x.iter().zip(y).map(|(x, y)| x + y).collect()
+}
+
+fn sub(x: &[f64], y: &[f64]) -> Vec<f64> {
+    x.iter().zip(y).map(|(x, y)| x - y).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, matrix::fill, Matrix, Norm};
+
+    #[test]
+    fn strassen() {
+        let a = matrix([[1., 2., 3.], [4., 5., 6.], [7., 8., 10.]]);
+        let b = matrix([[2., 0., 1.], [1., 3., -1.], [0., 5., 4.]]);
+        assert_eq!(a.strassen_with(&b, 1), a.multiply(&b));
+        assert_eq!(a.strassen(&b), a.multiply(&b));
+    }
+
+    #[test]
+    fn strassen_accuracy() {
+        let a: Matrix<37, 37> = fill(1);
+        let b: Matrix<37, 37> = fill(2);
+
+        let error = (a.strassen_with(&b, 4) - a.multiply(&b)).norm(Norm::One);
+        assert!(error <= 1e-12 * a.norm(Norm::One) * b.norm(Norm::One));
+    }
+}
@@ -0,0 +1,45 @@
+/// Work, in multiply-adds or copied elements, below which spawning tasks costs
+/// more than it saves.
+#[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+pub(crate) const THRESHOLD: usize = 1 << 15;
+
+/// Call `f` with the index of every element of `rows`.
+///
+/// With the `rayon` feature and at least `THRESHOLD` work the elements are
+/// split across threads. Each call only touches its own element, so the result
+/// doesn't depend on the path taken.
+pub(crate) fn for_each_row<T: Send>(
+    rows: &mut [T],
+    work: usize,
+    f: impl Fn(usize, &mut T) + Send + Sync,
+) {
+    #[cfg(feature = "rayon")]
+    if work >= THRESHOLD {
+        use rayon::prelude::*;
+        rows.par_iter_mut()
+            .enumerate()
+            .for_each(|(index, row)| f(index, row));
+        return;
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = work;
+
+    rows.iter_mut()
+        .enumerate()
+        .for_each(|(index, row)| f(index, row));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{for_each_row, THRESHOLD};
+
+    #[test]
+    fn for_each_row_case() {
+        let expected: Vec<f64> = (0..100).map(|index| (index as f64).sqrt()).collect();
+        for work in [0, THRESHOLD] {
+            let mut rows = vec![0.; 100];
+            for_each_row(&mut rows, work, |index, row| *row = (index as f64).sqrt());
+            assert_eq!(rows, expected);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{equation, line::Line, plane::Plane, Vector};
+
+const ZERO_DIRECTION_HAS_NO_NORMAL: &str = "Zero direction has no normal.";
+const PARALLEL_DIRECTIONS_HAVE_NO_NORMAL: &str = "Parallel directions have no normal.";
+
+/// The line `base_point + t * direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParametricLine<const DIM: usize> {
+    pub base_point: Vector<DIM>,
+    pub direction: Vector<DIM>,
+}
+
+/// The half line `origin + t * direction` with `t >= 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray<const DIM: usize> {
+    pub origin: Vector<DIM>,
+    pub direction: Vector<DIM>,
+}
+
+/// The plane `base_point + s * u + t * v`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParametricPlane {
+    pub base_point: Vector<3>,
+    pub u: Vector<3>,
+    pub v: Vector<3>,
+}
+
+/// Closest points of two lines, `self_point` on the first and `other_point` on the second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClosestApproach {
+    pub self_point: Vector<3>,
+    pub other_point: Vector<3>,
+    pub distance: f64,
+}
+
+pub fn parametric_line<const DIM: usize>(
+    base_point: Vector<DIM>,
+    direction: Vector<DIM>,
+) -> ParametricLine<DIM> {
+    ParametricLine {
+        base_point,
+        direction,
+    }
+}
+
+pub fn ray<const DIM: usize>(origin: Vector<DIM>, direction: Vector<DIM>) -> Ray<DIM> {
+    Ray { origin, direction }
+}
+
+pub fn parametric_plane(base_point: Vector<3>, u: Vector<3>, v: Vector<3>) -> ParametricPlane {
+    ParametricPlane { base_point, u, v }
+}
+
+/// Parameter of the point on `base_point + t * direction` closest to `point`,
+/// a zero direction gives `0`.
+fn closest_parameter<const DIM: usize>(
+    base_point: Vector<DIM>,
+    direction: Vector<DIM>,
+    point: &Vector<DIM>,
+) -> f64 {
+    let length_squared = direction.dot(&direction);
+    if length_squared == 0. {
+        return 0.;
+    }
+    (*point - base_point).dot(&direction) / length_squared
+}
+
+impl<const DIM: usize> ParametricLine<DIM> {
+    pub fn point_at(&self, t: f64) -> Vector<DIM> {
+        self.base_point + self.direction * t
+    }
+
+    pub fn closest_point(&self, point: &Vector<DIM>) -> Vector<DIM> {
+        self.point_at(closest_parameter(self.base_point, self.direction, point))
+    }
+
+    pub fn distance_to_point(&self, point: &Vector<DIM>) -> f64 {
+        (*point - self.closest_point(point)).magnitude()
+    }
+}
+
+impl<const DIM: usize> Ray<DIM> {
+    pub fn point_at(&self, t: f64) -> Vector<DIM> {
+        self.origin + self.direction * t
+    }
+
+    pub fn closest_point(&self, point: &Vector<DIM>) -> Vector<DIM> {
+        self.point_at(closest_parameter(self.origin, self.direction, point).max(0.))
+    }
+
+    pub fn distance_to_point(&self, point: &Vector<DIM>) -> f64 {
+        (*point - self.closest_point(point)).magnitude()
+    }
+}
+
+impl ParametricLine<2> {
+    pub fn to_implicit(&self) -> Result<Line, String> {
+        if self.direction.is_zero() {
+            return Err(String::from(ZERO_DIRECTION_HAS_NO_NORMAL));
+        }
+        let normal_vector = Vector::from([-self.direction[1], self.direction[0]]);
+        Ok(equation(normal_vector, normal_vector.dot(&self.base_point)))
+    }
+}
+
+impl ParametricLine<3> {
+    /// For parallel lines `self_point` is the base point of `self`.
+    pub fn closest_approach(&self, other: &Self) -> ClosestApproach {
+        let connect_vector = self.base_point - other.base_point;
+        let a = self.direction.dot(&self.direction);
+        let b = self.direction.dot(&other.direction);
+        let c = other.direction.dot(&other.direction);
+        let d = self.direction.dot(&connect_vector);
+        let e = other.direction.dot(&connect_vector);
+        let denominator = a * c - b * b;
+
+        // `a * c - b * b` is `a * c * sin²θ`, so compare it relative to `a * c`
+        // to keep short directions from looking parallel.
+        let (self_point, other_point) = if denominator <= 16. * f64::EPSILON * a * c {
+            (self.base_point, other.closest_point(&self.base_point))
+        } else {
+            let s = (b * e - c * d) / denominator;
+            let t = (a * e - b * d) / denominator;
+            (self.point_at(s), other.point_at(t))
+        };
+
+        ClosestApproach {
+            self_point,
+            other_point,
+            distance: (self_point - other_point).magnitude(),
+        }
+    }
+}
+
+impl ParametricPlane {
+    pub fn point_at(&self, s: f64, t: f64) -> Vector<3> {
+        self.base_point + self.u * s + self.v * t
+    }
+
+    pub fn to_implicit(&self) -> Result<Plane, String> {
+        let normal_vector = self.u.cross(&self.v);
+        if normal_vector.is_zero() {
+            return Err(String::from(PARALLEL_DIRECTIONS_HAVE_NO_NORMAL));
+        }
+        Ok(equation(normal_vector, normal_vector.dot(&self.base_point)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{equation, round::round_factory, vector};
+
+    use super::{parametric_line, parametric_plane, ray};
+
+    #[test]
+    fn point_at() {
+        let line = parametric_line(vector([1., 2., 3.]), vector([1., 0., -1.]));
+        assert_eq!(line.point_at(0.), vector([1., 2., 3.]));
+        assert_eq!(line.point_at(2.), vector([3., 2., 1.]));
+
+        let plane = parametric_plane(
+            vector([1., 1., 1.]),
+            vector([1., 0., 0.]),
+            vector([0., 1., 0.]),
+        );
+        assert_eq!(plane.point_at(2., -1.), vector([3., 0., 1.]));
+    }
+
+    #[test]
+    fn closest_point() {
+        let line = parametric_line(vector([0., 1.]), vector([2., 0.]));
+        assert_eq!(line.closest_point(&vector([-3., 4.])), vector([-3., 1.]));
+        assert_eq!(line.distance_to_point(&vector([-3., 4.])), 3.);
+
+        let r = ray(vector([0., 1.]), vector([2., 0.]));
+        assert_eq!(r.closest_point(&vector([-3., 4.])), vector([0., 1.]));
+        assert_eq!(r.distance_to_point(&vector([-3., 5.])), 5.);
+        assert_eq!(r.closest_point(&vector([3., 4.])), vector([3., 1.]));
+
+        let point = parametric_line(vector([1., 1., 1.]), vector([0., 0., 0.]));
+        assert_eq!(
+            point.closest_point(&vector([5., 5., 5.])),
+            vector([1., 1., 1.])
+        );
+
+        let short = parametric_line(vector([0., 0., 0.]), vector([1e-6, 0., 0.]));
+        assert_eq!(
+            short.closest_point(&vector([1., 1., 0.])).round(3),
+            vector([1., 0., 0.])
+        );
+        let short = ray(vector([0., 1.]), vector([-3e-7, 0.]));
+        assert_eq!(
+            short.closest_point(&vector([-2., 4.])).round(3),
+            vector([-2., 1.])
+        );
+    }
+
+    #[test]
+    fn closest_approach() {
+        let line_1 = parametric_line(vector([0., 0., 0.]), vector([1., 0., 0.]));
+        let line_2 = parametric_line(vector([0., 5., 2.]), vector([0., 1., 0.]));
+        let approach = line_1.closest_approach(&line_2);
+        assert_eq!(approach.self_point, vector([0., 0., 0.]));
+        assert_eq!(approach.other_point, vector([0., 0., 2.]));
+        assert_eq!(approach.distance, 2.);
+
+        let line_2 = parametric_line(vector([3., 0., 4.]), vector([-2., 0., 0.]));
+        let approach = line_1.closest_approach(&line_2);
+        assert_eq!(approach.self_point, vector([0., 0., 0.]));
+        assert_eq!(approach.other_point, vector([0., 0., 4.]));
+        assert_eq!(approach.distance, 4.);
+
+        let line_2 = parametric_line(vector([2., 3., 0.]), vector([1., 1., 0.]));
+        let round = round_factory(3);
+        assert_eq!(round(line_1.closest_approach(&line_2).distance), 0.);
+
+        let line_1 = parametric_line(vector([0., 0., 0.]), vector([1e-6, 0., 0.]));
+        let line_2 = parametric_line(vector([3., 5., 2.]), vector([0., 3e-6, 0.]));
+        let approach = line_1.closest_approach(&line_2);
+        assert_eq!(approach.self_point.round(3), vector([3., 0., 0.]));
+        assert_eq!(approach.other_point.round(3), vector([3., 0., 2.]));
+        assert_eq!(round(approach.distance), 2.);
+
+        let line_2 = parametric_line(vector([3., 0., 4.]), vector([-2e-6, 0., 0.]));
+        let approach = line_1.closest_approach(&line_2);
+        assert_eq!(approach.self_point, vector([0., 0., 0.]));
+        assert_eq!(approach.other_point.round(3), vector([0., 0., 4.]));
+    }
+
+    #[test]
+    fn to_implicit() {
+        let line = parametric_line(vector([0., 3.]), vector([1., 1.]));
+        assert_eq!(line.to_implicit(), Ok(equation(vector([1., -1.]), -3.)));
+        assert!(parametric_line(vector([0., 3.]), vector([0., 0.]))
+            .to_implicit()
+            .is_err());
+
+        let plane = parametric_plane(
+            vector([0., 0., 2.]),
+            vector([1., 0., 0.]),
+            vector([1., 1., 0.]),
+        );
+        assert_eq!(plane.to_implicit(), Ok(equation(vector([0., 0., 1.]), 2.)));
+        let plane = parametric_plane(
+            vector([0., 0., 2.]),
+            vector([1., 0., 0.]),
+            vector([2., 0., 0.]),
+        );
+        assert!(plane.to_implicit().is_err());
+    }
+}
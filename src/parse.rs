@@ -0,0 +1,333 @@
+use std::fmt::Display;
+
+/// Failure to read text, `line` and `column` start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Token {
+    Number(f64),
+    /// Index of `x_i`, `x`, `y` or `z`.
+    Variable(usize),
+    Plus,
+    Minus,
+    Star,
+    Equals,
+    LeftBracket,
+    RightBracket,
+    Comma,
+    Semicolon,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spanned {
+    pub(crate) token: Token,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl Spanned {
+    pub(crate) fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, message)
+    }
+}
+
+pub(crate) struct Parser<'a> {
+    tokens: &'a [Spanned],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(tokens: &'a [Spanned]) -> Self {
+        Parser {
+            tokens,
+            position: 0,
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Spanned {
+        self.tokens[self.position]
+    }
+
+    pub(crate) fn next(&mut self) -> Spanned {
+        let spanned = self.peek();
+        if spanned.token != Token::End {
+            self.position += 1;
+        }
+        spanned
+    }
+
+    pub(crate) fn expect(&mut self, token: Token, message: &str) -> Result<Spanned, ParseError> {
+        let spanned = self.next();
+        if spanned.token != token {
+            return Err(spanned.error(message));
+        }
+        Ok(spanned)
+    }
+
+    /// A number with an optional sign.
+    pub(crate) fn number(&mut self) -> Result<f64, ParseError> {
+        let sign = match self.peek().token {
+            Token::Plus => {
+                self.next();
+                1.
+            }
+            Token::Minus => {
+                self.next();
+                -1.
+            }
+            _ => 1.,
+        };
+        let spanned = self.next();
+        match spanned.token {
+            Token::Number(number) => Ok(sign * number),
+            _ => Err(spanned.error("expected a number")),
+        }
+    }
+
+    /// Numbers up to `]` or `;`, separated by commas or whitespace.
+    pub(crate) fn row(&mut self) -> Result<Vec<f64>, ParseError> {
+        let mut numbers = vec![];
+        while !matches!(
+            self.peek().token,
+            Token::RightBracket | Token::Semicolon | Token::End
+        ) {
+            if !numbers.is_empty() && self.peek().token == Token::Comma {
+                self.next();
+            }
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+}
+
+/// Splits `text` starting at `line` and `column`, the last token is always `Token::End`.
+pub(crate) fn tokenize(text: &str, line: usize, column: usize) -> Result<Vec<Spanned>, ParseError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let (mut line, mut column) = (line, column);
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+        let start = index;
+        let spanned = |token| Spanned {
+            token,
+            line,
+            column,
+        };
+
+        let token = match c {
+            '\n' => {
+                line += 1;
+                column = 1;
+                index += 1;
+                continue;
+            }
+            c if c.is_whitespace() => {
+                column += 1;
+                index += 1;
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '=' => Token::Equals,
+            // The bracket borders of the `Matrix` `Display` form.
+            '[' | '⎡' | '⎢' | '⎣' => Token::LeftBracket,
+            ']' | '⎤' | '⎥' | '⎦' => Token::RightBracket,
+            ',' => Token::Comma,
+            ';' => Token::Semicolon,
+            'N' | 'i' if word_at(&chars, index, "NaN") || word_at(&chars, index, "inf") => {
+                tokens.push(spanned(Token::Number(if c == 'N' {
+                    f64::NAN
+                } else {
+                    f64::INFINITY
+                })));
+                index += 3;
+                column += 3;
+                continue;
+            }
+            'x' if chars.get(index + 1) == Some(&'_') => {
+                index += 2;
+                let digits: String = chars[index..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+                if digits.is_empty() {
+                    return Err(ParseError::new(
+                        line,
+                        column + 2,
+                        "expected a variable index",
+                    ));
+                }
+                index += digits.len();
+                let variable = digits
+                    .parse()
+                    .map_err(|_| ParseError::new(line, column + 2, "invalid variable index"))?;
+                tokens.push(spanned(Token::Variable(variable)));
+                column += index - start;
+                continue;
+            }
+            'x' => Token::Variable(0),
+            'y' => Token::Variable(1),
+            'z' => Token::Variable(2),
+            c if c.is_ascii_digit() || c == '.' => {
+                index = number_end(&chars, index);
+                let number: String = chars[start..index].iter().collect();
+                let number = number.parse().map_err(|_| {
+                    ParseError::new(line, column, format!("invalid number `{}`", number))
+                })?;
+                tokens.push(spanned(Token::Number(number)));
+                column += index - start;
+                continue;
+            }
+            c => {
+                let message = format!("unexpected character `{}`", c);
+                return Err(ParseError::new(line, column, message));
+            }
+        };
+        tokens.push(spanned(token));
+        index += 1;
+        column += 1;
+    }
+
+    tokens.push(Spanned {
+        token: Token::End,
+        line,
+        column,
+    });
+    Ok(tokens)
+}
+
+fn word_at(chars: &[char], index: usize, word: &str) -> bool {
+    word.chars()
+        .enumerate()
+        .all(|(offset, c)| chars.get(index + offset) == Some(&c))
+}
+
+fn number_end(chars: &[char], mut index: usize) -> usize {
+    while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+        index += 1;
+    }
+    if matches!(chars.get(index), Some('e' | 'E')) {
+        let exponent = match chars.get(index + 1) {
+            Some('+' | '-') => index + 2,
+            _ => index + 1,
+        };
+        if chars.get(exponent).is_some_and(char::is_ascii_digit) {
+            index = exponent;
+            while index < chars.len() && chars[index].is_ascii_digit() {
+                index += 1;
+            }
+        }
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, ParseError, Parser, Token::*};
+
+    #[test]
+    fn tokenize_case() {
+        let tokens: Vec<_> = tokenize("-2.5e-1x_10 + y*3=\n z", 1, 1)
+            .unwrap()
+            .into_iter()
+            .map(|spanned| (spanned.token, spanned.line, spanned.column))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (Minus, 1, 1),
+                (Number(0.25), 1, 2),
+                (Variable(10), 1, 8),
+                (Plus, 1, 13),
+                (Variable(1), 1, 15),
+                (Star, 1, 16),
+                (Number(3.), 1, 17),
+                (Equals, 1, 18),
+                (Variable(2), 2, 2),
+                (End, 2, 3),
+            ]
+        );
+
+        assert_eq!(
+            tokenize("2x_a", 3, 5),
+            Err(ParseError::new(3, 8, "expected a variable index"))
+        );
+        assert_eq!(
+            tokenize("1.2.3", 1, 1),
+            Err(ParseError::new(1, 1, "invalid number `1.2.3`"))
+        );
+        assert_eq!(
+            tokenize("2w", 1, 1),
+            Err(ParseError::new(1, 2, "unexpected character `w`"))
+        );
+    }
+
+    #[test]
+    fn number() {
+        let tokens = tokenize("-1.5 +2 NaN inf -inf ,", 1, 1).unwrap();
+        let mut parser = Parser::new(&tokens);
+        assert_eq!(parser.number(), Ok(-1.5));
+        assert_eq!(parser.number(), Ok(2.));
+        assert!(parser.number().unwrap().is_nan());
+        assert_eq!(parser.number(), Ok(f64::INFINITY));
+        assert_eq!(parser.number(), Ok(f64::NEG_INFINITY));
+        assert_eq!(
+            parser.number(),
+            Err(ParseError::new(1, 22, "expected a number"))
+        );
+    }
+
+    #[test]
+    fn row() {
+        let tokens = tokenize("1, -2 3; 4]", 1, 1).unwrap();
+        let mut parser = Parser::new(&tokens);
+        assert_eq!(parser.row(), Ok(vec![1., -2., 3.]));
+        parser.next();
+        assert_eq!(parser.row(), Ok(vec![4.]));
+
+        let tokens = tokenize("1,, 2]", 1, 1).unwrap();
+        assert_eq!(
+            Parser::new(&tokens).row(),
+            Err(ParseError::new(1, 3, "expected a number"))
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{}", ParseError::new(2, 7, "expected `=`")),
+            "line 2, column 7: expected `=`"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    equation::{equation, Equation, ZERO_NORMAL_VECTOR_HAS_NO_BASE_POINT},
+    linear_system, math,
+    parametric::{parametric_line, parametric_plane, ParametricLine, ParametricPlane},
+    vector, Solution, Vector,
+};
+
+pub type Plane = Equation<3>;
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Intersection {
+    Some(ParametricLine<3>),
+    None,
+    Infinity(Plane),
+}
+
+impl Intersection {
+    pub fn unwrap(self) -> ParametricLine<3> {
+        use Intersection::*;
+        match self {
+            Some(val) => val,
+            None => panic!("called `Intersection::unwrap()` on a `None` value"),
+            Infinity(_plane) => panic!("called `Intersection::unwrap()` on a `Infinity` value"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineIntersection {
+    Some(Vector<3>),
+    None,
+    Infinity(ParametricLine<3>),
+}
+
+impl LineIntersection {
+    pub fn unwrap(self) -> Vector<3> {
+        use LineIntersection::*;
+        match self {
+            Some(val) => val,
+            None => panic!("called `LineIntersection::unwrap()` on a `None` value"),
+            Infinity(_line) => {
+                panic!("called `LineIntersection::unwrap()` on a `Infinity` value")
+            }
+        }
+    }
+}
+
+impl Plane {
+    /// The intersection line has its base point closest to the origin.
+    pub fn intersect(&self, other: &Self) -> Intersection {
+        use Intersection::*;
+
+        if self == other {
+            return Infinity(*self);
+        }
+
+        if self.is_parallel(other) {
+            return None;
+        }
+
+        let direction = self.normal_vector.cross(&other.normal_vector);
+        let system = linear_system([*self, *other, equation(direction, 0.)]);
+        match system.compute_solution() {
+            Solution::Some(base_point) => Some(parametric_line(base_point, direction)),
+            _ => None,
+        }
+    }
+
+    pub fn intersect_planes(&self, second: &Self, third: &Self) -> Solution<3> {
+        linear_system([*self, *second, *third]).compute_solution()
+    }
+
+    pub fn intersect_line(&self, line: &ParametricLine<3>) -> LineIntersection {
+        use LineIntersection::*;
+
+        let distance = self.constant_term - self.normal_vector.dot(&line.base_point);
+        let speed = self.normal_vector.dot(&line.direction);
+        if math::is_zero(speed) {
+            return if math::is_zero(distance) {
+                Infinity(*line)
+            } else {
+                None
+            };
+        }
+
+        Some(line.point_at(distance / speed))
+    }
+
+    pub fn to_parametric(&self) -> Result<ParametricPlane, String> {
+        let base_point = self
+            .base_point()
+            .ok_or(String::from(ZERO_NORMAL_VECTOR_HAS_NO_BASE_POINT))?;
+        let normal_vector = self.normal_vector;
+        let smallest_index = (0..3)
+            .min_by(|&i, &j| normal_vector[i].abs().total_cmp(&normal_vector[j].abs()))
+            .unwrap_or(0);
+        let mut axis = [0.; 3];
+        axis[smallest_index] = 1.;
+        let u = normal_vector.cross(&vector(axis));
+        let v = normal_vector.cross(&u);
+        Ok(parametric_plane(base_point, u, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{equation, parametric::parametric_line, vector, Solution};
+
+    #[test]
+    fn to_parametric() {
+        let plane = equation(vector([1., 2., 3.]), 6.);
+        let parametric = plane.to_parametric().unwrap();
+        assert_eq!(parametric.base_point, vector([6., 0., 0.]));
+        assert_eq!(parametric.u.dot(&vector([1., 2., 3.])), 0.);
+        assert_eq!(parametric.v.dot(&vector([1., 2., 3.])), 0.);
+        assert_eq!(parametric.to_implicit().unwrap(), plane);
+
+        assert!(equation(vector([0., 0., 0.]), 1.).to_parametric().is_err());
+    }
+
+    #[test]
+    fn intersect() {
+        use super::Intersection::*;
+
+        let plane_1 = equation(vector([-0.412, 3.806, 0.728]), -3.46);
+        let plane_2 = equation(vector([1.03, -9.515, -1.82]), 8.65);
+        assert_eq!(plane_1.intersect(&plane_2), Infinity(plane_1));
+
+        let plane_1 = equation(vector([2.611, 5.528, 0.283]), 4.6);
+        let plane_2 = equation(vector([7.715, 8.306, 5.342]), 3.76);
+        let line = plane_1.intersect(&plane_2).unwrap();
+        assert_eq!(line.base_point.round(3), vector([-0.067, 0.894, -0.589]));
+        assert_eq!(line.direction.round(3), vector([27.18, -11.765, -20.962]));
+
+        let plane_1 = equation(vector([-7.926, 8.625, -7.212]), -7.952);
+        let plane_2 = equation(vector([-2.642, 2.875, -2.404]), -2.443);
+        assert_eq!(plane_1.intersect(&plane_2), None);
+
+        let plane_1 = equation(vector([1., 1., 1.]), 3.);
+        let plane_2 = equation(vector([1., -1., 0.]), 0.);
+        let line = plane_1.intersect(&plane_2).unwrap();
+        assert_eq!(line.base_point.round(3), vector([1., 1., 1.]));
+        assert_eq!(line.direction, vector([1., 1., -2.]));
+    }
+
+    #[test]
+    fn intersect_planes() {
+        let plane_1 = equation(vector([1., 0., 0.]), 1.);
+        let plane_2 = equation(vector([0., 1., 0.]), 2.);
+        let plane_3 = equation(vector([0., 0., 1.]), 3.);
+        assert_eq!(
+            plane_1.intersect_planes(&plane_2, &plane_3),
+            Solution::Some(vector([1., 2., 3.]))
+        );
+
+        let plane_3 = equation(vector([1., 0., 0.]), 2.);
+        assert!(matches!(
+            plane_1.intersect_planes(&plane_2, &plane_3),
+            Solution::None(_)
+        ));
+    }
+
+    #[test]
+    fn intersect_line() {
+        use super::LineIntersection::*;
+
+        let plane = equation(vector([0., 0., 1.]), 2.);
+        let line = parametric_line(vector([0., 0., 0.]), vector([1., 1., 1.]));
+        assert_eq!(plane.intersect_line(&line), Some(vector([2., 2., 2.])));
+
+        let line = parametric_line(vector([0., 0., 2.]), vector([1., 0., 0.]));
+        assert_eq!(plane.intersect_line(&line), Infinity(line));
+
+        let line = parametric_line(vector([0., 0., 0.]), vector([1., 0., 0.]));
+        assert_eq!(plane.intersect_line(&line), None);
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{math::is_zero, Equation, LinearSystem, Matrix, Vector};
+
+/// LaTeX markup of the wrapped value.
+///
+/// The precision flag applies to every number, the alternate flag selects
+/// `bmatrix` over `pmatrix` and `aligned` over `cases`.
+pub struct Latex<'a, T: ?Sized>(&'a T);
+
+/// Presentation MathML of the wrapped value, with the same flags as `Latex`.
+pub struct MathMl<'a, T: ?Sized>(&'a T);
+
+pub trait Render {
+    fn to_latex(&self) -> Latex<'_, Self> {
+        Latex(self)
+    }
+
+    fn to_mathml(&self) -> MathMl<'_, Self> {
+        MathMl(self)
+    }
+}
+
+impl<const DIM: usize> Render for Vector<DIM> {}
+impl<const ROW: usize, const COL: usize> Render for Matrix<ROW, COL> {}
+impl<const DIM: usize> Render for Equation<DIM> {}
+impl<const DIM: usize, const LEN: usize> Render for LinearSystem<DIM, LEN> {}
+
+fn write_number(f: &mut Formatter<'_>, value: f64) -> Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*}", precision, value),
+        None => write!(f, "{}", value),
+    }
+}
+
+/// Nonzero terms as `(is_negative, |coefficient| unless it is 1, index)`.
+fn terms<const DIM: usize>(equation: &Equation<DIM>) -> Vec<(bool, Option<f64>, usize)> {
+    (0..DIM)
+        .map(|index| (equation.normal_vector[index], index))
+        .filter(|(coefficient, _)| !is_zero(*coefficient))
+        .map(|(coefficient, index)| {
+            let magnitude = coefficient.abs();
+            let magnitude = (!is_zero(magnitude - 1.)).then_some(magnitude);
+            (coefficient < 0., magnitude, index)
+        })
+        .collect()
+}
+
+fn write_latex_rows(f: &mut Formatter<'_>, rows: impl Iterator<Item = Vec<f64>>) -> Result {
+    let environment = if f.alternate() { "bmatrix" } else { "pmatrix" };
+    write!(f, "\\begin{{{}}}", environment)?;
+    for (index, row) in rows.enumerate() {
+        write!(f, "{}", if index == 0 { " " } else { " \\\\ " })?;
+        for (col, value) in row.into_iter().enumerate() {
+            if col != 0 {
+                write!(f, " & ")?;
+            }
+            write_number(f, value)?;
+        }
+    }
+    write!(f, " \\end{{{}}}", environment)
+}
+
+fn write_latex_equation<const DIM: usize>(
+    f: &mut Formatter<'_>,
+    equation: &Equation<DIM>,
+    equals: &str,
+) -> Result {
+    let terms = terms(equation);
+    if terms.is_empty() {
+        write!(f, "0")?;
+    }
+    for (index, (is_negative, magnitude, variable)) in terms.into_iter().enumerate() {
+        match (index == 0, is_negative) {
+            (true, true) => write!(f, "-")?,
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+            (true, false) => {}
+        }
+        if let Some(magnitude) = magnitude {
+            write_number(f, magnitude)?;
+        }
+        write!(f, "x_{{{}}}", variable)?;
+    }
+    write!(f, " {} ", equals)?;
+    write_number(f, equation.constant_term)
+}
+
+impl<const DIM: usize> Display for Latex<'_, Vector<DIM>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_latex_rows(f, self.0.into_iter().map(|value| vec![value]))
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Display for Latex<'_, Matrix<ROW, COL>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_latex_rows(f, self.0.into_iter().map(|row| row.into_iter().collect()))
+    }
+}
+
+impl<const DIM: usize> Display for Latex<'_, Equation<DIM>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_latex_equation(f, self.0, "=")
+    }
+}
+
+impl<const DIM: usize, const LEN: usize> Display for Latex<'_, LinearSystem<DIM, LEN>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (environment, equals) = if f.alternate() {
+            ("aligned", "&=")
+        } else {
+            ("cases", "=")
+        };
+        write!(f, "\\begin{{{}}}", environment)?;
+        for row in 0..LEN {
+            write!(f, "{}", if row == 0 { " " } else { " \\\\ " })?;
+            write_latex_equation(f, &self.0[row], equals)?;
+        }
+        write!(f, " \\end{{{}}}", environment)
+    }
+}
+
+fn write_mathml_number(f: &mut Formatter<'_>, value: f64) -> Result {
+    if value < 0. {
+        write!(f, "<mo>-</mo>")?;
+    }
+    write!(f, "<mn>")?;
+    write_number(f, value.abs())?;
+    write!(f, "</mn>")
+}
+
+fn write_mathml_rows(f: &mut Formatter<'_>, rows: impl Iterator<Item = Vec<f64>>) -> Result {
+    let (open, close) = if f.alternate() {
+        ("[", "]")
+    } else {
+        ("(", ")")
+    };
+    write!(f, "<math><mrow><mo>{}</mo><mtable>", open)?;
+    for row in rows {
+        write!(f, "<mtr>")?;
+        for value in row {
+            write!(f, "<mtd>")?;
+            write_mathml_number(f, value)?;
+            write!(f, "</mtd>")?;
+        }
+        write!(f, "</mtr>")?;
+    }
+    write!(f, "</mtable><mo>{}</mo></mrow></math>", close)
+}
+
+fn write_mathml_equation<const DIM: usize>(
+    f: &mut Formatter<'_>,
+    equation: &Equation<DIM>,
+) -> Result {
+    write!(f, "<mrow>")?;
+    let terms = terms(equation);
+    if terms.is_empty() {
+        write!(f, "<mn>0</mn>")?;
+    }
+    for (index, (is_negative, magnitude, variable)) in terms.into_iter().enumerate() {
+        if is_negative {
+            write!(f, "<mo>-</mo>")?;
+        } else if index != 0 {
+            write!(f, "<mo>+</mo>")?;
+        }
+        if let Some(magnitude) = magnitude {
+            write_mathml_number(f, magnitude)?;
+        }
+        write!(f, "<msub><mi>x</mi><mn>{}</mn></msub>", variable)?;
+    }
+    write!(f, "<mo>=</mo>")?;
+    write_mathml_number(f, equation.constant_term)?;
+    write!(f, "</mrow>")
+}
+
+impl<const DIM: usize> Display for MathMl<'_, Vector<DIM>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_mathml_rows(f, self.0.into_iter().map(|value| vec![value]))
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Display for MathMl<'_, Matrix<ROW, COL>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_mathml_rows(f, self.0.into_iter().map(|row| row.into_iter().collect()))
+    }
+}
+
+impl<const DIM: usize> Display for MathMl<'_, Equation<DIM>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "<math>")?;
+        write_mathml_equation(f, self.0)?;
+        write!(f, "</math>")
+    }
+}
+
+/// A brace and a left aligned table, the alternate flag drops the brace.
+impl<const DIM: usize, const LEN: usize> Display for MathMl<'_, LinearSystem<DIM, LEN>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "<math><mrow>")?;
+        if !f.alternate() {
+            write!(f, "<mo>{{</mo>")?;
+        }
+        write!(f, "<mtable columnalign=\"left\">")?;
+        for row in 0..LEN {
+            write!(f, "<mtr><mtd>")?;
+            write_mathml_equation(f, &self.0[row])?;
+            write!(f, "</mtd></mtr>")?;
+        }
+        write!(f, "</mtable></mrow></math>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{equation, linear_system, matrix, vector};
+
+    use super::Render;
+
+    #[test]
+    fn latex() {
+        let v = vector([2., 1., 3.]);
+        assert_eq!(
+            format!("{}", v.to_latex()),
+            "\\begin{pmatrix} 2 \\\\ 1 \\\\ 3 \\end{pmatrix}"
+        );
+
+        let m = matrix([[2.53, 1.], [-5., 7.]]);
+        assert_eq!(
+            format!("{:.1}", m.to_latex()),
+            "\\begin{pmatrix} 2.5 & 1.0 \\\\ -5.0 & 7.0 \\end{pmatrix}"
+        );
+        assert_eq!(
+            format!("{:#}", m.to_latex()),
+            "\\begin{bmatrix} 2.53 & 1 \\\\ -5 & 7 \\end{bmatrix}"
+        );
+
+        let e = equation(vector([-0.12, 2.3, -1.]), 5.4);
+        assert_eq!(
+            format!("{}", e.to_latex()),
+            "-0.12x_{0} + 2.3x_{1} - x_{2} = 5.4"
+        );
+        assert_eq!(
+            format!("{}", equation(vector([0., 0.]), 1.).to_latex()),
+            "0 = 1"
+        );
+
+        let s = linear_system([
+            equation(vector([1., 1.]), 1.),
+            equation(vector([0., 2.]), 2.),
+        ]);
+        assert_eq!(
+            format!("{}", s.to_latex()),
+            "\\begin{cases} x_{0} + x_{1} = 1 \\\\ 2x_{1} = 2 \\end{cases}"
+        );
+        assert_eq!(
+            format!("{:#.1}", s.to_latex()),
+            "\\begin{aligned} x_{0} + x_{1} &= 1.0 \\\\ 2.0x_{1} &= 2.0 \\end{aligned}"
+        );
+    }
+
+    #[test]
+    fn mathml() {
+        let v = vector([2., -1.]);
+        assert_eq!(
+            format!("{}", v.to_mathml()),
+            "<math><mrow><mo>(</mo><mtable>\
+             <mtr><mtd><mn>2</mn></mtd></mtr>\
+             <mtr><mtd><mo>-</mo><mn>1</mn></mtd></mtr>\
+             </mtable><mo>)</mo></mrow></math>"
+        );
+
+        let m = matrix([[1.25, 0.]]);
+        assert_eq!(
+            format!("{:#.1}", m.to_mathml()),
+            "<math><mrow><mo>[</mo><mtable>\
+             <mtr><mtd><mn>1.2</mn></mtd><mtd><mn>0.0</mn></mtd></mtr>\
+             </mtable><mo>]</mo></mrow></math>"
+        );
+
+        let e = equation(vector([-2., 1.]), 3.);
+        assert_eq!(
+            format!("{}", e.to_mathml()),
+            "<math><mrow><mo>-</mo><mn>2</mn><msub><mi>x</mi><mn>0</mn></msub>\
+             <mo>+</mo><msub><mi>x</mi><mn>1</mn></msub><mo>=</mo><mn>3</mn></mrow></math>"
+        );
+
+        let s = linear_system([equation(vector([1.]), 1.)]);
+        assert_eq!(
+            format!("{}", s.to_mathml()),
+            "<math><mrow><mo>{</mo><mtable columnalign=\"left\">\
+             <mtr><mtd><mrow><msub><mi>x</mi><mn>0</mn></msub><mo>=</mo><mn>1</mn></mrow></mtd></mtr>\
+             </mtable></mrow></math>"
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{equation, linear_system, matrix, Equation, LinearSystem, Matrix, Vector};
+
+/// Fixed-size arrays as tuples, deserializing checks the length.
+pub(crate) mod array {
+    use std::marker::PhantomData;
+
+    use serde::{
+        de::{self, IgnoredAny, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    pub(crate) fn serialize<S, T, const N: usize>(
+        array: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for element in array {
+            tuple.serialize_element(element)?;
+        }
+        tuple.end()
+    }
+
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+        type Value = [T; N];
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "an array of length {}", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut elements = Vec::with_capacity(N);
+            while elements.len() < N {
+                match seq.next_element()? {
+                    Some(element) => elements.push(element),
+                    None => return Err(de::Error::invalid_length(elements.len(), &self)),
+                }
+            }
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+            Ok(elements
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("exactly N elements were read")))
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D, T, const N: usize>(
+        deserializer: D,
+    ) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+    }
+}
+
+/// Finite values as numbers. Human-readable formats get `NaN` and the
+/// infinities as the strings `"NaN"`, `"inf"` and `"-inf"`, since formats like
+/// JSON have no number for them, binary formats keep them as floats.
+pub(crate) mod number {
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_f64(*value);
+        }
+        match *value {
+            value if value.is_nan() => serializer.serialize_str("NaN"),
+            f64::INFINITY => serializer.serialize_str("inf"),
+            f64::NEG_INFINITY => serializer.serialize_str("-inf"),
+            value => serializer.serialize_f64(value),
+        }
+    }
+
+    struct NumberVisitor;
+
+    impl Visitor<'_> for NumberVisitor {
+        type Value = f64;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "a number, \"NaN\", \"inf\" or \"-inf\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<f64, E> {
+            match value {
+                "NaN" => Ok(f64::NAN),
+                "inf" => Ok(f64::INFINITY),
+                "-inf" => Ok(f64::NEG_INFINITY),
+                _ => Err(de::Error::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+
+    /// Binary formats keep non-finite values as floats and can't be asked to
+    /// guess the type, so only human-readable ones accept the strings.
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NumberVisitor)
+        } else {
+            deserializer.deserialize_f64(NumberVisitor)
+        }
+    }
+
+    /// An element of the arrays written by `super::array`.
+    pub(crate) struct Number(pub(crate) f64);
+
+    impl Serialize for Number {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Number {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(Number)
+        }
+    }
+}
+
+impl<const DIM: usize> Serialize for Vector<DIM> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let elements: [number::Number; DIM] =
+            std::array::from_fn(|index| number::Number(self[index]));
+        array::serialize(&elements, serializer)
+    }
+}
+
+impl<'de, const DIM: usize> Deserialize<'de> for Vector<DIM> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements: [number::Number; DIM] = array::deserialize(deserializer)?;
+        Ok(crate::vector(elements.map(|number| number.0)))
+    }
+}
+
+impl<const ROW: usize, const COL: usize> Serialize for Matrix<ROW, COL> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows: [Vector<COL>; ROW] = std::array::from_fn(|row| self[row]);
+        array::serialize(&rows, serializer)
+    }
+}
+
+impl<'de, const ROW: usize, const COL: usize> Deserialize<'de> for Matrix<ROW, COL> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows: [Vector<COL>; ROW] = array::deserialize(deserializer)?;
+        Ok(matrix(rows.map(|row| std::array::from_fn(|col| row[col]))))
+    }
+}
+
+/// The serialized form of `Equation`, without the derived base point.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Equation")]
+struct EquationData<const DIM: usize> {
+    normal_vector: Vector<DIM>,
+    #[serde(with = "number")]
+    constant_term: f64,
+}
+
+impl<const DIM: usize> Serialize for Equation<DIM> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EquationData {
+            normal_vector: self.normal_vector,
+            constant_term: self.constant_term,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// The base point is rebuilt rather than read.
+impl<'de, const DIM: usize> Deserialize<'de> for Equation<DIM> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = EquationData::deserialize(deserializer)?;
+        Ok(equation(data.normal_vector, data.constant_term))
+    }
+}
+
+impl<const DIM: usize, const LEN: usize> Serialize for LinearSystem<DIM, LEN> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let equations: [Equation<DIM>; LEN] = std::array::from_fn(|row| self[row]);
+        array::serialize(&equations, serializer)
+    }
+}
+
+impl<'de, const DIM: usize, const LEN: usize> Deserialize<'de> for LinearSystem<DIM, LEN> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        array::deserialize(deserializer).map(linear_system)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        equation, line::Intersection, linear_system, matrix, vector, ConditionedSolution, Equation,
+        LinearSystem, Matrix, Solution, SolutionKind, Solutions, Vector,
+    };
+
+    #[test]
+    fn vector_case() {
+        let v = vector([2., 1., 3.]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[2.0,1.0,3.0]");
+        assert_eq!(serde_json::from_str::<Vector<3>>(&json).unwrap(), v);
+        assert!(serde_json::from_str::<Vector<3>>("[2.0,1.0]").is_err());
+        assert!(serde_json::from_str::<Vector<2>>(&json).is_err());
+    }
+
+    #[test]
+    fn matrix_case() {
+        let m = matrix([[2., 1., 3.], [5., 7., 8.]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[2.0,1.0,3.0],[5.0,7.0,8.0]]");
+        assert_eq!(serde_json::from_str::<Matrix<2, 3>>(&json).unwrap(), m);
+        assert!(serde_json::from_str::<Matrix<3, 2>>(&json).is_err());
+    }
+
+    #[test]
+    fn equation_case() {
+        let e = equation(vector([0., 2.]), 6.);
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, r#"{"normal_vector":[0.0,2.0],"constant_term":6.0}"#);
+        let loaded: Equation<2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.base_point(), Some(vector([0., 3.])));
+
+        let json = r#"{"normal_vector":[0.0,2.0],"constant_term":6.0,"base_point":[9.0,9.0]}"#;
+        let loaded: Equation<2> = serde_json::from_str(json).unwrap();
+        assert_eq!(loaded.base_point(), Some(vector([0., 3.])));
+    }
+
+    #[test]
+    fn linear_system_case() {
+        let e1 = equation(vector([1., 1.]), 3.);
+        let e2 = equation(vector([1., -1.]), 1.);
+        let s = linear_system([e1, e2]);
+        let json = serde_json::to_string(&s).unwrap();
+        let loaded: LinearSystem<2, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded[0], e1);
+        assert_eq!(loaded[1], e2);
+        assert!(serde_json::from_str::<LinearSystem<2, 3>>(&json).is_err());
+
+        let solution = s.compute_solution();
+        let json = serde_json::to_string(&solution).unwrap();
+        assert_eq!(json, r#"{"Some":[2.0,1.0]}"#);
+        assert_eq!(
+            serde_json::from_str::<Solution<2>>(&json).unwrap(),
+            solution
+        );
+
+        let s = linear_system([e1, e1 * 2.]);
+        let solutions = s.coefficient_matrix().solve(&matrix([[3., 1.], [7., 2.]]));
+        assert_eq!(
+            solutions.kinds,
+            [SolutionKind::None, SolutionKind::Infinity]
+        );
+        let json = serde_json::to_string(&solutions).unwrap();
+        let loaded: Solutions<2, 2> = serde_json::from_str(&json).unwrap();
+        assert!(loaded.values.get_col(0).into_iter().all(f64::is_nan));
+        assert_eq!(loaded.values.get_col(1), solutions.values.get_col(1));
+        assert_eq!(loaded.kinds, solutions.kinds);
+        assert_eq!(loaded.classifications, solutions.classifications);
+    }
+
+    #[test]
+    fn non_finite_case() {
+        let v = vector([f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"["NaN","inf","-inf",1.0]"#);
+        let loaded: Vector<4> = serde_json::from_str(&json).unwrap();
+        assert!(loaded[0].is_nan());
+        assert_eq!([loaded[1], loaded[2], loaded[3]], [v[1], v[2], v[3]]);
+        assert_eq!(
+            serde_json::from_str::<Vector<2>>("[1, -2]").unwrap(),
+            vector([1., -2.])
+        );
+        assert!(serde_json::from_str::<Vector<1>>(r#"["nan"]"#).is_err());
+
+        let e = equation(vector([1., 0.]), f64::INFINITY);
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, r#"{"normal_vector":[1.0,0.0],"constant_term":"inf"}"#);
+        let loaded: Equation<2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.constant_term, f64::INFINITY);
+
+        let s = linear_system([
+            equation(vector([4., 2.]), 1.),
+            equation(vector([14., 7.]), 1.),
+        ]);
+        let conditioned = s.compute_conditioned_solution();
+        let json = serde_json::to_string(&conditioned).unwrap();
+        let loaded: ConditionedSolution<2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, conditioned);
+    }
+
+    #[test]
+    fn bincode_case() {
+        let v = vector([f64::NAN, 2.]);
+        let bytes = bincode::serialize(&v).unwrap();
+        assert_eq!(bytes.len(), 16);
+        let loaded: Vector<2> = bincode::deserialize(&bytes).unwrap();
+        assert!(loaded[0].is_nan());
+        assert_eq!(loaded[1], 2.);
+
+        let m = matrix([[2., f64::INFINITY], [5., 7.]]);
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bincode::deserialize::<Matrix<2, 2>>(&bytes).unwrap(), m);
+
+        let e1 = equation(vector([1., 1.]), 3.);
+        let e2 = equation(vector([1., -1.]), 1.);
+        let s = linear_system([e1, e2]);
+        let bytes = bincode::serialize(&s).unwrap();
+        let loaded: LinearSystem<2, 2> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(loaded[0], e1);
+        assert_eq!(loaded[1], e2);
+
+        let solution = s.compute_solution();
+        let bytes = bincode::serialize(&solution).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Solution<2>>(&bytes).unwrap(),
+            solution
+        );
+    }
+
+    #[test]
+    fn intersection_case() {
+        let line = equation(vector([1., 1.]), 3.);
+        let intersection = Intersection::Infinity(line);
+        let json = serde_json::to_string(&intersection).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Intersection>(&json).unwrap(),
+            intersection
+        );
+    }
+}
@@ -1,10 +1,13 @@
 mod fmt;
 mod iter;
 mod ops;
+mod parse;
 
-use std::f64::consts::PI;
-
-use crate::{math, round::round_factory};
+use crate::{
+    math::{self, Summation, Tolerance},
+    round::round_factory,
+    Matrix,
+};
 
 const ZERO_VECTOR_HAS_NO_NORMALIZE: &str = "Zero vector has no normalize.";
 
@@ -12,6 +15,7 @@ const ZERO_VECTOR_HAS_NO_NORMALIZE: &str = "Zero vector has no normalize.";
 pub struct Vector<const DIM: usize>([f64; DIM]);
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Projection<const DIM: usize> {
     pub parallel: Vector<DIM>,
     pub orthogonal: Vector<DIM>,
@@ -27,11 +31,27 @@ pub fn vector<const DIM: usize>(arr: [f64; DIM]) -> Vector<DIM> {
     Vector::from(arr)
 }
 
+/// `vector![2, 1, 3]`, the elements are converted with `as f64`.
+#[macro_export]
+macro_rules! vector {
+    ($($x:expr),* $(,)?) => {
+        $crate::vector([$(($x) as f64),*])
+    };
+}
+
 impl<const DIM: usize> Vector<DIM> {
     pub fn dim(&self) -> usize {
         DIM
     }
 
+    pub(crate) fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+
     pub fn map<F>(&self, f: F) -> Self
     where
         F: Fn(f64) -> f64,
@@ -48,12 +68,49 @@ impl<const DIM: usize> Vector<DIM> {
         self.map(|x| x * scalar)
     }
 
+    /// Element-wise product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.into_iter().zip(*other).map(|(x, y)| x * y).collect()
+    }
+
+    /// Element-wise quotient.
+    pub fn component_div(&self, other: &Self) -> Self {
+        self.into_iter().zip(*other).map(|(x, y)| x / y).collect()
+    }
+
     pub fn magnitude(&self) -> f64 {
-        self.into_iter().map(|x| x.powi(2)).sum::<f64>().sqrt()
+        self.magnitude_with(Summation::Naive)
+    }
+
+    /// `Summation::Scaled` is `hypot`-like, finite for every finite vector and
+    /// nonzero for every nonzero one.
+    pub fn magnitude_with(&self, summation: Summation) -> f64 {
+        if summation != Summation::Scaled {
+            return summation.sum(self.into_iter().map(|x| x.powi(2))).sqrt();
+        }
+        let scale = math::max_abs(*self);
+        if scale == 0. || scale.is_infinite() {
+            return scale;
+        }
+        scale
+            * summation
+                .sum(self.into_iter().map(|x| (x / scale).powi(2)))
+                .sqrt()
     }
 
     pub fn normalize(&self) -> Result<Self, String> {
-        let magnitude = self.magnitude();
+        self.normalize_with(Summation::Naive)
+    }
+
+    /// With `Summation::Scaled` only the exact zero vector has no normalize.
+    pub fn normalize_with(&self, summation: Summation) -> Result<Self, String> {
+        let magnitude = self.magnitude_with(summation);
+        if summation == Summation::Scaled {
+            if magnitude == 0. {
+                return Err(String::from(ZERO_VECTOR_HAS_NO_NORMALIZE));
+            }
+            return Ok(self.map(|x| x / magnitude));
+        }
         if math::is_zero(magnitude) {
             return Err(String::from(ZERO_VECTOR_HAS_NO_NORMALIZE));
         }
@@ -61,11 +118,43 @@ impl<const DIM: usize> Vector<DIM> {
     }
 
     pub fn dot(&self, other: &Self) -> f64 {
-        let mut dot_product = 0.;
-        self.into_iter()
-            .enumerate()
-            .for_each(|(index, _)| dot_product += self[index] * other[index]);
-        dot_product
+        self.dot_with(other, Summation::Naive)
+    }
+
+    pub fn dot_with(&self, other: &Self, summation: Summation) -> f64 {
+        if summation != Summation::Scaled {
+            return summation.sum(self.into_iter().zip(*other).map(|(x, y)| x * y));
+        }
+        let scale = math::max_abs(*self);
+        let other_scale = math::max_abs(*other);
+        if scale == 0. || other_scale == 0. {
+            return 0.;
+        }
+        if !scale.is_finite() || !other_scale.is_finite() {
+            return self.dot_with(other, Summation::Naive);
+        }
+        let products = self
+            .into_iter()
+            .zip(*other)
+            .map(|(x, y)| (x / scale) * (y / other_scale));
+        scale * (other_scale * summation.sum(products))
+    }
+
+    /// `self * otherᵀ`, row `i` is `other` scaled by `self[i]`.
+    pub fn outer<const M: usize>(&self, other: &Vector<M>) -> Matrix<DIM, M> {
+        self.into_iter().map(|x| other.scale(x)).collect()
+    }
+
+    pub fn to_col_matrix(&self) -> Matrix<DIM, 1> {
+        self.into_iter().map(|x| vector([x])).collect()
+    }
+
+    pub fn to_row_matrix(&self) -> Matrix<1, DIM> {
+        Matrix::from_rows([*self])
+    }
+
+    pub fn from_row_matrix(matrix: &Matrix<1, DIM>) -> Self {
+        matrix[0]
     }
 
     /// return the angle between the two vectors in radian.
@@ -91,11 +180,19 @@ impl<const DIM: usize> Vector<DIM> {
     }
 
     pub fn is_parallel(&self, other: &Self) -> bool {
-        let angle = self.angle(other);
-        match angle {
-            Ok(rad) => math::is_zero(rad) || math::eq(rad, PI),
-            Err(_) => true,
+        self.is_parallel_with(other, &Tolerance::default())
+    }
+
+    /// Zero vectors are parallel to every vector, otherwise the cosine of the
+    /// angle between the vectors is compared with `±1` under `tolerance`.
+    pub fn is_parallel_with(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        let magnitude = self.magnitude();
+        let other_magnitude = other.magnitude();
+        if tolerance.is_zero(magnitude) || tolerance.is_zero(other_magnitude) {
+            return true;
         }
+        let cos = self.dot(other) / (magnitude * other_magnitude);
+        tolerance.approx_eq(cos, 1.) || tolerance.approx_eq(cos, -1.)
     }
 
     pub fn is_orthogonal_with_tolerance(&self, other: &Self, tolerance: Option<f64>) -> bool {
@@ -139,7 +236,11 @@ impl Vector<3> {
 #[cfg(test)]
 mod tests {
     use super::ZERO_VECTOR_HAS_NO_NORMALIZE;
-    use crate::{math::to_deg, round::round_factory, vector};
+    use crate::{
+        math::{to_deg, Summation, Tolerance},
+        round::round_factory,
+        vector,
+    };
 
     #[test]
     fn dim() {
@@ -194,6 +295,48 @@ mod tests {
         assert_eq!(u, vector([0.34, 0.53, -0.777]));
     }
 
+    #[test]
+    fn summation() {
+        let huge = vector([1e200, 1e200]);
+        assert_eq!(huge.magnitude(), f64::INFINITY);
+        assert_eq!(huge.magnitude_with(Summation::Scaled), 1e200 * 2f64.sqrt());
+        let tiny = vector([3e-200, 4e-200]);
+        assert_eq!(tiny.magnitude(), 0.);
+        assert_eq!(tiny.magnitude_with(Summation::Scaled), 5e-200);
+        assert_eq!(
+            tiny.normalize_with(Summation::Scaled).unwrap().round(3),
+            vector([0.6, 0.8])
+        );
+        assert_eq!(
+            tiny.normalize(),
+            Err(String::from(ZERO_VECTOR_HAS_NO_NORMALIZE))
+        );
+
+        let v = vector([1e16, 1., -1e16]);
+        let w = vector([1., 1., 1.]);
+        assert_eq!(v.dot(&w), 0.);
+        assert_eq!(v.dot_with(&w, Summation::Compensated), 1.);
+        assert_eq!(v.dot_with(&w, Summation::Pairwise), 0.);
+        assert!(huge.dot(&vector([1e200, -1e200])).is_nan());
+        assert_eq!(
+            huge.dot_with(&vector([1e200, -1e200]), Summation::Scaled),
+            0.
+        );
+        assert_eq!(vector([3., 4.]).magnitude_with(Summation::Compensated), 5.);
+
+        assert_eq!(huge.magnitude_with(Summation::Compensated), f64::INFINITY);
+        let infinite = vector([f64::INFINITY, 1.]);
+        assert_eq!(
+            infinite.dot_with(&vector([1., 1.]), Summation::Scaled),
+            f64::INFINITY
+        );
+        assert_eq!(
+            vector([1., 1.]).dot_with(&infinite, Summation::Scaled),
+            f64::INFINITY
+        );
+        assert_eq!(infinite.magnitude_with(Summation::Scaled), f64::INFINITY);
+    }
+
     #[test]
     fn normalize_zero() {
         let v = vector([0., 0.]);
@@ -245,6 +388,21 @@ mod tests {
         assert!(v.is_orthogonal(&w));
     }
 
+    #[test]
+    fn is_parallel_with() {
+        let v = vector([1e-12, 2e-12]);
+        let w = vector([1e-12, -2e-12]);
+        assert!(v.is_parallel(&w));
+        assert!(!v.is_parallel_with(&w, &Tolerance::Relative(1e-10)));
+        assert!(v.is_parallel_with(&(v * 3.), &Tolerance::Relative(1e-10)));
+        assert!(v.is_parallel_with(&(v * -3.), &Tolerance::Ulps(4)));
+
+        let v = vector([1., 0.]);
+        let w = vector([1., 1e-4]);
+        assert!(!v.is_parallel(&w));
+        assert!(v.is_parallel_with(&w, &Tolerance::Absolute(1e-6)));
+    }
+
     #[test]
     fn project() {
         let v = vector([3.039, 1.879]);
@@ -302,4 +460,11 @@ mod tests {
         let w = vector([-6.007, 0.124, 5.772]);
         assert_eq!(round(v.area_of_triangle(&w)), 42.565);
     }
+
+    #[test]
+    fn vector_macro() {
+        assert_eq!(crate::vector![2, 1, 3], vector([2., 1., 3.]));
+        assert_eq!(crate::vector![2.5, -1, 1 + 2,], vector([2.5, -1., 3.]));
+        assert_eq!(crate::vector![], vector([]));
+    }
 }
@@ -1,4 +1,6 @@
-use std::ops::{Add, Index, Mul, Sub};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 use crate::Vector;
 
@@ -10,14 +12,33 @@ impl<const DIM: usize> Index<usize> for Vector<DIM> {
     }
 }
 
+impl<const DIM: usize> IndexMut<usize> for Vector<DIM> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const DIM: usize> Add for &Vector<DIM> {
+    type Output = Vector<DIM>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.0.iter().zip(&rhs.0).map(|(x, y)| x + y).collect()
+    }
+}
+
 impl<const DIM: usize> Add for Vector<DIM> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.into_iter()
-            .enumerate()
-            .map(|(index, _)| self[index] + rhs[index])
-            .collect()
+        Add::add(&self, &rhs)
+    }
+}
+
+impl<const DIM: usize> Sub for &Vector<DIM> {
+    type Output = Vector<DIM>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0.iter().zip(&rhs.0).map(|(x, y)| x - y).collect()
     }
 }
 
@@ -25,10 +46,15 @@ impl<const DIM: usize> Sub for Vector<DIM> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.into_iter()
-            .enumerate()
-            .map(|(index, _)| self[index] - rhs[index])
-            .collect()
+        Sub::sub(&self, &rhs)
+    }
+}
+
+impl<const DIM: usize> Mul<f64> for &Vector<DIM> {
+    type Output = Vector<DIM>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scale(rhs)
     }
 }
 
@@ -36,7 +62,99 @@ impl<const DIM: usize> Mul<f64> for Vector<DIM> {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        self.scale(rhs)
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl<const DIM: usize> Mul<&Vector<DIM>> for f64 {
+    type Output = Vector<DIM>;
+
+    fn mul(self, rhs: &Vector<DIM>) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+impl<const DIM: usize> Mul<Vector<DIM>> for f64 {
+    type Output = Vector<DIM>;
+
+    fn mul(self, rhs: Vector<DIM>) -> Self::Output {
+        Mul::mul(self, &rhs)
+    }
+}
+
+impl<const DIM: usize> Div<f64> for &Vector<DIM> {
+    type Output = Vector<DIM>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(|x| x / rhs)
+    }
+}
+
+impl<const DIM: usize> Div<f64> for Vector<DIM> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Div::div(&self, rhs)
+    }
+}
+
+impl<const DIM: usize> Neg for &Vector<DIM> {
+    type Output = Vector<DIM>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<const DIM: usize> Neg for Vector<DIM> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<const DIM: usize> AddAssign<&Vector<DIM>> for Vector<DIM> {
+    fn add_assign(&mut self, rhs: &Vector<DIM>) {
+        self.0.iter_mut().zip(&rhs.0).for_each(|(x, y)| *x += y);
+    }
+}
+
+impl<const DIM: usize> AddAssign for Vector<DIM> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<const DIM: usize> SubAssign<&Vector<DIM>> for Vector<DIM> {
+    fn sub_assign(&mut self, rhs: &Vector<DIM>) {
+        self.0.iter_mut().zip(&rhs.0).for_each(|(x, y)| *x -= y);
+    }
+}
+
+impl<const DIM: usize> SubAssign for Vector<DIM> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<const DIM: usize> MulAssign<f64> for Vector<DIM> {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0.iter_mut().for_each(|x| *x *= rhs);
+    }
+}
+
+impl<const DIM: usize> DivAssign<f64> for Vector<DIM> {
+    fn div_assign(&mut self, rhs: f64) {
+        self.0.iter_mut().for_each(|x| *x /= rhs);
+    }
+}
+
+impl<const DIM: usize> Mul for &Vector<DIM> {
+    type Output = f64;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.dot(rhs)
     }
 }
 
@@ -44,7 +162,7 @@ impl<const DIM: usize> Mul for Vector<DIM> {
     type Output = f64;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self.dot(&rhs)
+        Mul::mul(&self, &rhs)
     }
 }
 
@@ -105,4 +223,51 @@ mod tests {
         let w = vector([-4.496, -8.755, 7.103]);
         assert_eq!(round(v.dot(&w)), 56.397);
     }
+
+    #[test]
+    fn index_mut() {
+        let mut v = vector([2., 3., 0.]);
+        v[1] = 5.;
+        assert_eq!(v, vector([2., 5., 0.]));
+    }
+
+    #[test]
+    fn neg_div() {
+        let v = vector([2., -3.]);
+        assert_eq!(-v, vector([-2., 3.]));
+        assert_eq!(v / 2., vector([1., -1.5]));
+        assert_eq!(2. * v, v * 2.);
+    }
+
+    #[test]
+    fn assign() {
+        let mut v = vector([1., 2.]);
+        v += vector([3., 4.]);
+        assert_eq!(v, vector([4., 6.]));
+        v -= vector([1., 1.]);
+        assert_eq!(v, vector([3., 5.]));
+        v *= 2.;
+        assert_eq!(v, vector([6., 10.]));
+        v /= 4.;
+        assert_eq!(v, vector([1.5, 2.5]));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference() {
+        let a = vector([1., 2.]);
+        let b = vector([3., -4.]);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a * 2., a * 2.);
+        assert_eq!(2. * &a, 2. * a);
+        assert_eq!(&a / 2., a / 2.);
+        assert_eq!(-&a, -a);
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
 }
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{tokenize, ParseError, Parser, Token},
+    vector, Vector,
+};
+
+/// `[2, 1, 3]` as printed by `Display`, or `[2 1 3]`.
+impl<const DIM: usize> FromStr for Vector<DIM> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s, 1, 1)?;
+        let mut parser = Parser::new(&tokens);
+        let start = parser.expect(Token::LeftBracket, "expected `[`")?;
+        let numbers = parser.row()?;
+        parser.expect(Token::RightBracket, "expected `]`")?;
+        parser.expect(Token::End, "expected the end of the vector")?;
+
+        let len = numbers.len();
+        let numbers: [f64; DIM] = numbers
+            .try_into()
+            .map_err(|_| start.error(format!("expected {} elements, found {}", DIM, len)))?;
+        Ok(vector(numbers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse::ParseError, vector, Vector};
+
+    #[test]
+    fn from_str() {
+        assert_eq!("[2, 1, 3]".parse(), Ok(vector([2., 1., 3.])));
+        assert_eq!("[2 -1 3.5e1]".parse(), Ok(vector([2., -1., 35.])));
+        assert_eq!("[]".parse(), Ok(vector([])));
+
+        let v = vector([2.53, -1.7823, 3.161]);
+        assert_eq!(format!("{}", v).parse(), Ok(v));
+    }
+
+    #[test]
+    fn from_str_error() {
+        let parse = |s: &str| s.parse::<Vector<3>>();
+        assert_eq!(
+            parse("[2, 1]"),
+            Err(ParseError::new(1, 1, "expected 3 elements, found 2"))
+        );
+        assert_eq!(parse("2, 1, 3"), Err(ParseError::new(1, 1, "expected `[`")));
+        assert_eq!(
+            parse("[2, x, 3]"),
+            Err(ParseError::new(1, 5, "expected a number"))
+        );
+        assert_eq!(
+            parse("[2; 1, 3]"),
+            Err(ParseError::new(1, 3, "expected `]`"))
+        );
+        assert_eq!(
+            parse("[2, 1, 3]]"),
+            Err(ParseError::new(1, 10, "expected the end of the vector"))
+        );
+    }
+}